@@ -1,4 +1,4 @@
-use tidos::{scoped_css, view, Component, Page};
+use tidos::{scoped_css, view, Component, Page, Props};
 
 use crate::components::feature_card::FeatureCard;
 
@@ -31,6 +31,7 @@ const FEATURES: &[Feature] = &[
     },
 ];
 
+#[derive(Props)]
 pub struct FeaturesSection;
 
 impl Component for FeaturesSection {
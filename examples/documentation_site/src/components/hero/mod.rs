@@ -1,8 +1,9 @@
 use crate::components::install_section::InstallPill;
-use tidos::{scoped_css, view, Component, Page};
+use tidos::{scoped_css, view, Component, Page, Props};
 
 use crate::components::nav_bar::NavBar;
 
+#[derive(Props)]
 pub struct Hero;
 
 impl Component for Hero {
@@ -1,8 +1,9 @@
-use tidos::{native_element, scoped_css, view, Component, Page};
+use tidos::{native_element, scoped_css, view, Component, Page, Props};
 
 #[native_element]
 pub struct InstallPill;
 
+#[derive(Props)]
 pub struct InstallSection;
 
 impl Component for InstallSection {
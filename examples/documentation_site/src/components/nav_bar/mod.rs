@@ -1,7 +1,8 @@
-use tidos::{head, scoped_css, view, Component, Page};
+use tidos::{head, scoped_css, view, Component, Page, Props};
 
 use crate::components::mobile_menu::MobileMenu;
 
+#[derive(Props)]
 pub struct NavBar;
 
 impl Component for NavBar {
@@ -18,7 +19,7 @@ impl Component for NavBar {
                     <span class="inko"><img src="/inko.svg" alt={""} /></span>
                     <span class="name">{"Tidos"}</span>
                 </div>
-                <MobileMenu controls={String::from("main-nav")} label={String::from("Toggle navigation menu")} />
+                <MobileMenu controls="main-nav" label="Toggle navigation menu" />
                 <nav id="main-nav">
                     <a href="/docs/getting-started">{"documentation"}</a>
                     <a href="/news">{"news"}</a>
@@ -29,6 +30,7 @@ impl Component for NavBar {
     }
 }
 
+#[derive(Props)]
 pub struct NavBarDocs;
 
 impl Component for NavBarDocs {
@@ -45,7 +47,7 @@ impl Component for NavBarDocs {
                     <span class="inko"><img src="/inko.svg" alt={""} /></span>
                     <span class="name">{"Tidos"}</span>
                 </a>
-                <MobileMenu controls={String::from("docs-nav")} label={String::from("Toggle navigation menu")} />
+                <MobileMenu controls="docs-nav" label="Toggle navigation menu" />
                 <nav id="docs-nav">
                     <a href="/docs/getting-started">{"documentation"}</a>
                     <a href="/news">{"news"}</a>
@@ -1,5 +1,6 @@
-use tidos::{head, scoped_css, view, Component, Page, Slot};
+use tidos::{head, scoped_css, view, Component, Page, Props, Slot};
 
+#[derive(Props)]
 pub struct NewsLayout<'a> {
     pub content: Slot<'a>,
 }
@@ -1,5 +1,6 @@
-use tidos::{scoped_css, view, Component, Page};
+use tidos::{scoped_css, view, Component, Page, Props};
 
+#[derive(Props)]
 pub struct SiteFooter;
 
 impl Component for SiteFooter {
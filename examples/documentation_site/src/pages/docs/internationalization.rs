@@ -2,7 +2,7 @@ use crate::components::code_block::CodeBlock;
 use crate::components::docs_layout::DocsLayout;
 use crate::components::nav_bar::NavBarDocs;
 use rocket::get;
-use tidos::{head, page, view, Component, Page};
+use tidos::{head, highlight, page, view, Component, Page, Props};
 
 #[get("/docs/internationalization")]
 pub fn internationalization() -> Page {
@@ -25,6 +25,7 @@ pub fn internationalization() -> Page {
     page
 }
 
+#[derive(Props)]
 struct I18nContent;
 
 impl Component for I18nContent {
@@ -33,19 +34,19 @@ impl Component for I18nContent {
             <title>{"Internationalization - Tidos"}</title>
         }
 
-        let cargo_toml = "[dependencies]\ntidos = { version = \"0.8.0-rc.1\", features = [\"rocket\", \"i18n\"] }";
+        let cargo_toml = highlight!("toml", "[dependencies]\ntidos = { version = \"0.8.0-rc.1\", features = [\"rocket\", \"i18n\"] }");
 
-        let tidos_toml = "[default]\nresource_location = \"translations\"\ndefault_locale = \"en-US\"\nresources = [\"common.ftl\"]";
+        let tidos_toml = highlight!("toml", "[default]\nresource_location = \"translations\"\ndefault_locale = \"en-US\"\nresources = [\"common.ftl\"]");
 
-        let file_tree = "translations/\n\u{251C}\u{2500}\u{2500} en-US/\n\u{2502}   \u{2514}\u{2500}\u{2500} common.ftl\n\u{2514}\u{2500}\u{2500} nl-NL/\n    \u{2514}\u{2500}\u{2500} common.ftl";
+        let file_tree = highlight!("text", "translations/\n\u{251C}\u{2500}\u{2500} en-US/\n\u{2502}   \u{2514}\u{2500}\u{2500} common.ftl\n\u{2514}\u{2500}\u{2500} nl-NL/\n    \u{2514}\u{2500}\u{2500} common.ftl");
 
-        let ftl_example = "# translations/en-US/common.ftl\ngreeting = Hello, { $name }!\n\nwelcome-message =\n    Welcome back, { $name }.\n    You have { $count ->\n        [one] one new message\n       *[other] { $count } new messages\n    }.";
+        let ftl_example = highlight!("text", "# translations/en-US/common.ftl\ngreeting = Hello, { $name }!\n\nwelcome-message =\n    Welcome back, { $name }.\n    You have { $count ->\n        [one] one new message\n       *[other] { $count } new messages\n    }.");
 
-        let enable_i18n = "// main.rs\nuse tidos::i18n::enable_i18n;\n\nenable_i18n!();\n\n#[rocket::main]\nasync fn main() {\n    rocket::build()\n        .mount(\"/\", routes![index])\n        .launch()\n        .await\n        .unwrap();\n}";
+        let enable_i18n = highlight!("rust", "// main.rs\nuse tidos::i18n::enable_i18n;\n\nenable_i18n!();\n\n#[rocket::main]\nasync fn main() {\n    rocket::build()\n        .mount(\"/\", routes![index])\n        .launch()\n        .await\n        .unwrap();\n}");
 
-        let route_example = "use tidos::i18n::Lang;\nuse tidos::{page, Page};\nuse rocket::get;\n\n// The locale is the first path segment: /en-US or /nl-NL\n#[get(\"/<lang>\")]\npub fn index(lang: Lang) -> Page {\n    page! {\n        <main>\n            <Greeting />\n        </main>\n    }\n}";
+        let route_example = highlight!("rust", "use tidos::i18n::Lang;\nuse tidos::{page, Page};\nuse rocket::get;\n\n// The locale is the first path segment: /en-US or /nl-NL\n#[get(\"/<lang>\")]\npub fn index(lang: Lang) -> Page {\n    page! {\n        <main>\n            <Greeting />\n        </main>\n    }\n}");
 
-        let component_example = "use tidos::{view, Component, Page};\nuse tidos::i18n::i18n;\n\npub struct Greeting;\n\nimpl Component for Greeting {\n    fn to_render(&self, page: &mut Page) {\n        view! {\n            <section>\n                // Simple lookup\n                <h1>{i18n!(\"greeting\", (\"name\", \"Alice\"))}</h1>\n\n                // Lookup with plural variable\n                <p>{i18n!(\"welcome-message\", (\"name\", \"Alice\"), (\"count\", 3))}</p>\n            </section>\n        }\n    }\n}";
+        let component_example = highlight!("rust", "use tidos::{view, Component, Page};\nuse tidos::i18n::i18n;\n\npub struct Greeting;\n\nimpl Component for Greeting {\n    fn to_render(&self, page: &mut Page) {\n        view! {\n            <section>\n                // Simple lookup\n                <h1>{i18n!(\"greeting\", (\"name\", \"Alice\"))}</h1>\n\n                // Lookup with plural variable\n                <p>{i18n!(\"welcome-message\", (\"name\", \"Alice\"), (\"count\", 3))}</p>\n            </section>\n        }\n    }\n}");
 
         view! {
             <div class="doc-content">
@@ -53,28 +54,28 @@ impl Component for I18nContent {
                 <p>{"Tidos provides built-in i18n support via "}<a href="https://projectfluent.org/">{"Fluent"}</a>{". Enable it with the i18n feature flag to get locale-aware translations, pluralization, and gender variants."}</p>
 
                 <h2>{"1. Enable the Feature"}</h2>
-                <CodeBlock code={cargo_toml.to_string()} />
+                <CodeBlock code={cargo_toml} />
 
                 <h2>{"2. Create Tidos.toml"}</h2>
                 <p>{"Place Tidos.toml in your project root to configure translation file locations:"}</p>
-                <CodeBlock code={tidos_toml.to_string()} />
+                <CodeBlock code={tidos_toml} />
 
                 <h2>{"3. Add Translation Files"}</h2>
                 <p>{"Organize .ftl files by locale under the translations directory:"}</p>
-                <CodeBlock code={file_tree.to_string()} />
-                <CodeBlock code={ftl_example.to_string()} />
+                <CodeBlock code={file_tree} />
+                <CodeBlock code={ftl_example} />
 
                 <h2 id="the-i18n-macro">{"4. Initialize in main.rs"}</h2>
                 <p>{"Call enable_i18n! once at startup to load the translation configuration:"}</p>
-                <CodeBlock code={enable_i18n.to_string()} />
+                <CodeBlock code={enable_i18n} />
 
                 <h2>{"5. Add Lang to Routes"}</h2>
                 <p>{"The locale is expected as the first URL path segment. Add lang: Lang to your route handler and page! picks it up automatically:"}</p>
-                <CodeBlock code={route_example.to_string()} />
+                <CodeBlock code={route_example} />
 
                 <h2>{"6. Translate with i18n!"}</h2>
                 <p>{"Call i18n! inside any component with a Fluent message key and optional variable pairs:"}</p>
-                <CodeBlock code={component_example.to_string()} />
+                <CodeBlock code={component_example} />
             </div>
         }
     }
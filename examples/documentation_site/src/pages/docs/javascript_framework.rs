@@ -3,7 +3,7 @@ use crate::components::docs_layout::DocsLayout;
 use crate::components::framework_tabs::FrameworkTabs;
 use crate::components::nav_bar::NavBarDocs;
 use rocket::get;
-use tidos::{head, native_element, page, view, Component, Page};
+use tidos::{head, highlight, native_element, page, view, Component, Markup, Page, Props};
 
 #[get("/docs/javascript-framework?<framework>")]
 pub fn javascript_framework(framework: Option<String>) -> Page {
@@ -30,10 +30,11 @@ pub fn javascript_framework(framework: Option<String>) -> Page {
 
 struct JsFwDocs {
     name: &'static str,
-    code: &'static str,
-    vite_config: &'static str,
+    code: Markup,
+    vite_config: Markup,
 }
 
+#[derive(Props)]
 struct JsFrameworkContent {
     pub active: String,
 }
@@ -50,45 +51,45 @@ impl Component for JsFrameworkContent {
             <title>{"Reactivity - Tidos"}</title>
         }
         
-        let native_element_rs = "use tidos::native_element;\n\n// GreetUser -> /dist/GreetUser.js -> <greet-user>\n#[native_element]\npub struct GreetUser {\n    pub name: String,\n    pub is_admin: bool,\n}\n\n// Output of macro\nimpl tidos::Component for GreetUser {\n    fn to_render(&self, page: &mut tidos::Page) {\n        tidos::head!{ <script r#type=\"module\" src=\"/dist/GreetUser.js\"></script> };\n        tidos::view!{ <greet-user name={self.name} :is-admin={self.is_admin}></greet-user> };\n    }\n}";
+        let native_element_rs = highlight!("rust", "use tidos::native_element;\n\n// GreetUser -> /dist/GreetUser.js -> <greet-user>\n#[native_element]\npub struct GreetUser {\n    pub name: String,\n    pub is_admin: bool,\n}\n\n// Output of macro\nimpl tidos::Component for GreetUser {\n    fn to_render(&self, page: &mut tidos::Page) {\n        tidos::head!{ <script r#type=\"module\" src=\"/dist/GreetUser.js\"></script> };\n        tidos::view!{ <greet-user name={self.name} :is-admin={self.is_admin}></greet-user> };\n    }\n}");
 
-        let serve_dist = "// Rocket — serve compiled JS from the dist folder\n#[get(\"/dist/<file..>\")]\nasync fn dist_files(file: std::path::PathBuf) -> Option<rocket::fs::NamedFile> {\n    rocket::fs::NamedFile::open(std::path::Path::new(\"dist/\").join(file)).await.ok()\n}";
+        let serve_dist = highlight!("rust", "// Rocket — serve compiled JS from the dist folder\n#[get(\"/dist/<file..>\")]\nasync fn dist_files(file: std::path::PathBuf) -> Option<rocket::fs::NamedFile> {\n    rocket::fs::NamedFile::open(std::path::Path::new(\"dist/\").join(file)).await.ok()\n}");
 
         // The full, working Vite configs, pulled straight from the example
         // projects so they stay in sync and can be copy-pasted as-is.
-        let vite_svelte = include_str!("../../../../web_frameworks/svelte/vite.config.js");
-        let vite_lit = include_str!("../../../../web_frameworks/lit/vite.config.js");
+        let vite_svelte = highlight!("js", file = "../../../../web_frameworks/svelte/vite.config.js");
+        let vite_lit = highlight!("js", file = "../../../../web_frameworks/lit/vite.config.js");
 
-        let vite_angular = include_str!("../../../../web_frameworks/angular/vite.config.js");
+        let vite_angular = highlight!("js", file = "../../../../web_frameworks/angular/vite.config.js");
 
-        let vite_vue = include_str!("../../../../web_frameworks/vue/vite.config.js");
+        let vite_vue = highlight!("js", file = "../../../../web_frameworks/vue/vite.config.js");
 
-        let vite_react = include_str!("../../../../web_frameworks/react/vite.config.js");
+        let vite_react = highlight!("js", file = "../../../../web_frameworks/react/vite.config.js");
 
         let frameworks = vec![
             JsFwDocs {
                 name: "Svelte",
-                code: "<!-- src/components/GreetUser.svelte -->\n<svelte:options customElement=\"greet-user\"></svelte:options>\n\n<script>\n    let { name = '', is_admin = false } = $props();\n</script>\n\n<div>\n    <p>Hello, {name}!</p>\n    {#if is_admin}\n        <span class=\"badge\">Admin</span>\n    {/if}\n</div>\n\n<style>\n    :host { display: block; }\n    .badge { background: #4fd1c5; color: #000; padding: 0.2rem 0.5rem; border-radius: 4px; }\n</style>",
+                code: highlight!("html", "<!-- src/components/GreetUser.svelte -->\n<svelte:options customElement=\"greet-user\"></svelte:options>\n\n<script>\n    let { name = '', is_admin = false } = $props();\n</script>\n\n<div>\n    <p>Hello, {name}!</p>\n    {#if is_admin}\n        <span class=\"badge\">Admin</span>\n    {/if}\n</div>\n\n<style>\n    :host { display: block; }\n    .badge { background: #4fd1c5; color: #000; padding: 0.2rem 0.5rem; border-radius: 4px; }\n</style>"),
                 vite_config: vite_svelte,
             },
             JsFwDocs {
                 name: "Lit",
-                code: "// src/components/GreetUser.ts\nimport { LitElement, html } from 'lit';\nimport { customElement, property } from 'lit/decorators.js';\n\n@customElement('greet-user')\nexport class GreetUser extends LitElement {\n    @property() name = '';\n    @property({ type: Boolean }) isAdmin = false;\n\n    render() {\n        return html`\n            <p>Hello, ${this.name}!</p>\n            ${this.isAdmin ? html`<span class=\"badge\">Admin</span>` : ''}\n        `;\n    }\n}",
+                code: highlight!("js", "// src/components/GreetUser.ts\nimport { LitElement, html } from 'lit';\nimport { customElement, property } from 'lit/decorators.js';\n\n@customElement('greet-user')\nexport class GreetUser extends LitElement {\n    @property() name = '';\n    @property({ type: Boolean }) isAdmin = false;\n\n    render() {\n        return html`\n            <p>Hello, ${this.name}!</p>\n            ${this.isAdmin ? html`<span class=\"badge\">Admin</span>` : ''}\n        `;\n    }\n}"),
                 vite_config: vite_lit,
             },
             JsFwDocs {
                 name: "Angular",
-                code: "// app.module.ts — register as Angular Element\nimport { createCustomElement } from '@angular/elements';\nimport { GreetUserComponent } from './greet-user.component';\n\n@NgModule({ ... })\nexport class AppModule {\n    constructor(private injector: Injector) {}\n    ngDoBootstrap() {\n        const el = createCustomElement(GreetUserComponent, { injector: this.injector });\n        customElements.define('greet-user', el);\n    }\n}",
+                code: highlight!("js", "// app.module.ts — register as Angular Element\nimport { createCustomElement } from '@angular/elements';\nimport { GreetUserComponent } from './greet-user.component';\n\n@NgModule({ ... })\nexport class AppModule {\n    constructor(private injector: Injector) {}\n    ngDoBootstrap() {\n        const el = createCustomElement(GreetUserComponent, { injector: this.injector });\n        customElements.define('greet-user', el);\n    }\n}"),
                 vite_config: vite_angular,
             },
             JsFwDocs {
                 name: "Vue",
-                code: "<!-- src/components/GreetUser.vue -->\n<template>\n    <div>\n        <p>Hello, {{ name }}!</p>\n        <span v-if=\"isAdmin\" class=\"badge\">Admin</span>\n    </div>\n</template>\n\n<script setup>\ndefineProps({\n    name: { type: String, default: '' },\n    isAdmin: { type: Boolean, default: false },\n});\n</script>\n\n<!-- src/components/GreetUser.js — wrapper that registers the SFC as a custom element -->\nimport { defineCustomElement } from 'vue';\nimport GreetUser from './GreetUser.vue';\n\ncustomElements.define('greet-user', defineCustomElement(GreetUser));",
+                code: highlight!("html", "<!-- src/components/GreetUser.vue -->\n<template>\n    <div>\n        <p>Hello, {{ name }}!</p>\n        <span v-if=\"isAdmin\" class=\"badge\">Admin</span>\n    </div>\n</template>\n\n<script setup>\ndefineProps({\n    name: { type: String, default: '' },\n    isAdmin: { type: Boolean, default: false },\n});\n</script>\n\n<!-- src/components/GreetUser.js — wrapper that registers the SFC as a custom element -->\nimport { defineCustomElement } from 'vue';\nimport GreetUser from './GreetUser.vue';\n\ncustomElements.define('greet-user', defineCustomElement(GreetUser));"),
                 vite_config: vite_vue,
             },
             JsFwDocs {
                 name: "React",
-                code: "// src/components/GreetUser.tsx\nimport { createRoot } from 'react-dom/client';\n\nclass GreetUser extends HTMLElement {\n    connectedCallback() {\n        const name = this.getAttribute('name') || '';\n        const isAdmin = this.hasAttribute('is-admin');\n        const root = createRoot(this);\n        root.render(\n            <div>\n                <p>Hello, {name}!</p>\n                {isAdmin && <span className=\"badge\">Admin</span>}\n            </div>\n        );\n    }\n}\n\ncustomElements.define('greet-user', GreetUser);",
+                code: highlight!("js", "// src/components/GreetUser.tsx\nimport { createRoot } from 'react-dom/client';\n\nclass GreetUser extends HTMLElement {\n    connectedCallback() {\n        const name = this.getAttribute('name') || '';\n        const isAdmin = this.hasAttribute('is-admin');\n        const root = createRoot(this);\n        root.render(\n            <div>\n                <p>Hello, {name}!</p>\n                {isAdmin && <span className=\"badge\">Admin</span>}\n            </div>\n        );\n    }\n}\n\ncustomElements.define('greet-user', GreetUser);"),
                 vite_config: vite_react,
             },
         ];
@@ -112,13 +113,13 @@ impl Component for JsFrameworkContent {
 
                 <h2>{"The Rust Wrapper"}</h2>
                 <p>{"#[native_element] derives Component for the struct. It injects the script tag and renders the kebab-case custom element with all fields forwarded as HTML attributes:"}</p>
-                <CodeBlock code={native_element_rs.to_string()} />
+                <CodeBlock code={native_element_rs} />
 
                 <h2>{"Framework Example"}</h2>
                 <FrameworkTabs frameworks={frameworks_str} active={active_clone} />
                 {#for fw in &frameworks}
                     <div data-framework={fw.name} style={if fw.name == active { "display:block" } else { "display:none" }}>
-                        <CodeBlock code={fw.code.to_string()} />
+                        <CodeBlock code={fw.code.clone()} />
                     </div>
                 {/for}
 
@@ -126,13 +127,13 @@ impl Component for JsFrameworkContent {
                 <p>{"Compile the component to dist/ComponentName.js using Vite:"}</p>
                 {#for fw in &frameworks}
                     <div data-framework={fw.name} style={if fw.name == active { "display:block" } else { "display:none" }}>
-                        <CodeBlock code={fw.vite_config.to_string()} />
+                        <CodeBlock code={fw.vite_config.clone()} />
                     </div>
                 {/for}
 
                 <h2>{"Serving Built Files"}</h2>
                 <p>{"Add a route in your Rust server to serve files from the dist directory:"}</p>
-                <CodeBlock code={serve_dist.to_string()} />
+                <CodeBlock code={serve_dist} />
             </div>
         }
     }
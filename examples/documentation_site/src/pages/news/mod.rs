@@ -7,7 +7,7 @@ pub mod v0_8_0_rc2;
 use crate::components::nav_bar::NavBarDocs;
 use crate::components::news_layout::NewsLayout;
 use rocket::get;
-use tidos::{head, page, view, Component, Page};
+use tidos::{head, page, view, Component, Page, Props};
 
 #[get("/news")]
 pub fn news_index() -> Page {
@@ -31,6 +31,7 @@ pub fn news_index() -> Page {
     page
 }
 
+#[derive(Props)]
 struct NewsIndexContent;
 
 impl Component for NewsIndexContent {
@@ -2,7 +2,7 @@ use crate::components::code_block::CodeBlock;
 use crate::components::nav_bar::NavBarDocs;
 use crate::components::news_layout::NewsLayout;
 use rocket::get;
-use tidos::{head, page, view, Component, Page};
+use tidos::{head, highlight, page, view, Component, Page, Props};
 
 #[get("/news/v0-7-0")]
 pub fn news_v0_7_0() -> Page {
@@ -26,19 +26,20 @@ pub fn news_v0_7_0() -> Page {
     page
 }
 
+#[derive(Props)]
 struct ArticleContent;
 
 impl Component for ArticleContent {
     fn to_render(&self, page: &mut Page) {
-        let code_cargo = "[dependencies]\ntidos = { version = \"0.7.0\", features = [\"rocket\", \"i18n\"] }";
-        let code_tidos_toml = "[default]\nresource_location = \"translations\"\ndefault_locale = \"en-US\"\nresources = [\"common.ftl\"]";
-        let code_enable = "use tidos::i18n::enable_i18n;\n\nenable_i18n!();\n\n#[rocket::main]\nasync fn main() { /* ... */ }";
-        let code_simple = "use tidos::i18n::i18n;\n\nimpl Component for Greeting {\n    fn to_render(&self, page: &mut Page) {\n        view! {\n            <h1>{i18n!(\"greeting\")}</h1>\n        }\n    }\n}";
-        let code_vars = "// Fluent message with variable:\n// welcome = Welcome back, { $name }!\n\nview! {\n    <p>{i18n!(\"welcome\", \"name\", &self.username)}</p>\n}";
-        let code_ftl_dir = "translations/\n  en-US/\n    common.ftl\n  nl-NL/\n    common.ftl";
-        let code_plural = "new-messages =\n    { $count ->\n        [one]  You have one new message.\n       *[other] You have { $count } new messages.\n    }";
-        let code_lang = "use tidos::i18n::Lang;\n\n#[get(\"/<lang>/dashboard\")]\npub fn dashboard(lang: Lang) -> Page {\n    page! {\n        <main>\n            <h1>{i18n!(\"dashboard-title\")}</h1>\n        </main>\n    }\n}";
-        let code_upgrade = "[dependencies]\ntidos = \"0.7.0\"  # existing usage unaffected";
+        let code_cargo = highlight!("toml", "[dependencies]\ntidos = { version = \"0.7.0\", features = [\"rocket\", \"i18n\"] }");
+        let code_tidos_toml = highlight!("toml", "[default]\nresource_location = \"translations\"\ndefault_locale = \"en-US\"\nresources = [\"common.ftl\"]");
+        let code_enable = highlight!("rust", "use tidos::i18n::enable_i18n;\n\nenable_i18n!();\n\n#[rocket::main]\nasync fn main() { /* ... */ }");
+        let code_simple = highlight!("rust", "use tidos::i18n::i18n;\n\nimpl Component for Greeting {\n    fn to_render(&self, page: &mut Page) {\n        view! {\n            <h1>{i18n!(\"greeting\")}</h1>\n        }\n    }\n}");
+        let code_vars = highlight!("rust", "// Fluent message with variable:\n// welcome = Welcome back, { $name }!\n\nview! {\n    <p>{i18n!(\"welcome\", \"name\", &self.username)}</p>\n}");
+        let code_ftl_dir = highlight!("text", "translations/\n  en-US/\n    common.ftl\n  nl-NL/\n    common.ftl");
+        let code_plural = highlight!("text", "new-messages =\n    { $count ->\n        [one]  You have one new message.\n       *[other] You have { $count } new messages.\n    }");
+        let code_lang = highlight!("rust", "use tidos::i18n::Lang;\n\n#[get(\"/<lang>/dashboard\")]\npub fn dashboard(lang: Lang) -> Page {\n    page! {\n        <main>\n            <h1>{i18n!(\"dashboard-title\")}</h1>\n        </main>\n    }\n}");
+        let code_upgrade = highlight!("toml", "[dependencies]\ntidos = \"0.7.0\"  # existing usage unaffected");
 
         view! {
             <div class="article-content">
@@ -54,32 +55,32 @@ impl Component for ArticleContent {
 
                 <h2>{"Enabling i18n"}</h2>
                 <p>{"Add the i18n feature flag to your Cargo.toml:"}</p>
-                <CodeBlock code={code_cargo.to_string()} />
+                <CodeBlock code={code_cargo} />
                 <p>{"Create a Tidos.toml in your project root to configure the locale directory and default language:"}</p>
-                <CodeBlock code={code_tidos_toml.to_string()} />
+                <CodeBlock code={code_tidos_toml} />
                 <p>{"Then call enable_i18n! once at the top of main.rs to load the translation files at startup:"}</p>
-                <CodeBlock code={code_enable.to_string()} />
+                <CodeBlock code={code_enable} />
 
                 <h2>{"Translating strings with i18n!"}</h2>
                 <p>{"Inside any component, import and call i18n! with a Fluent message key:"}</p>
-                <CodeBlock code={code_simple.to_string()} />
+                <CodeBlock code={code_simple} />
                 <p>{"Variables are passed as alternating key-value pairs after the message key:"}</p>
-                <CodeBlock code={code_vars.to_string()} />
+                <CodeBlock code={code_vars} />
 
                 <h2>{"Translation files"}</h2>
                 <p>{"Fluent .ftl files live under your resource_location, organised by locale:"}</p>
-                <CodeBlock code={code_ftl_dir.to_string()} />
+                <CodeBlock code={code_ftl_dir} />
                 <p>{"Fluent supports plural forms, gender variants, and other selectors out of the box, so you can handle complex grammatical rules without hand-written conditionals:"}</p>
-                <CodeBlock code={code_plural.to_string()} />
+                <CodeBlock code={code_plural} />
 
                 <h2>{"Locale routing with Lang"}</h2>
                 <p>{"Add a Lang parameter to your route to accept a locale prefix in the URL. Tidos picks up the active locale automatically inside page!:"}</p>
-                <CodeBlock code={code_lang.to_string()} />
+                <CodeBlock code={code_lang} />
                 <p>{"The lang parameter doubles as a Rocket request guard and as the active locale context for all i18n! calls on that page."}</p>
 
                 <h2>{"Upgrading"}</h2>
                 <p>{"No breaking changes for existing Tidos users. The i18n feature is entirely opt-in:"}</p>
-                <CodeBlock code={code_upgrade.to_string()} />
+                <CodeBlock code={code_upgrade} />
             </div>
         }
     }
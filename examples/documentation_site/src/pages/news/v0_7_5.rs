@@ -2,7 +2,7 @@ use crate::components::code_block::CodeBlock;
 use crate::components::nav_bar::NavBarDocs;
 use crate::components::news_layout::NewsLayout;
 use rocket::get;
-use tidos::{head, page, view, Component, Page};
+use tidos::{head, highlight, page, view, Component, Page, Props};
 
 #[get("/news/v0-7-5")]
 pub fn news_v0_7_5() -> Page {
@@ -26,13 +26,14 @@ pub fn news_v0_7_5() -> Page {
     page
 }
 
+#[derive(Props)]
 struct ArticleContent;
 
 impl Component for ArticleContent {
     fn to_render(&self, page: &mut Page) {
-        let code_cargo_frameworks = "# Axum\ntidos = { version = \"0.7.5\", features = [\"axum\"] }\n\n# Actix-web\ntidos = { version = \"0.7.5\", features = [\"actix-web\"] }\n\n# Warp\ntidos = { version = \"0.7.5\", features = [\"warp\"] }\n\n# Rocket (unchanged)\ntidos = { version = \"0.7.5\", features = [\"rocket\"] }";
-        let code_native_element = "use tidos::native_element;\n\n#[native_element]\npub struct MyCounter {\n    pub initial: i32,\n    pub step: i32,\n}\n\n// Renders: <my-counter initial=\"0\" step=\"1\"></my-counter>\n// and injects: <script src=\"/dist/my-counter.js\"></script>";
-        let code_cargo = "# Feature flag for your framework of choice\ntidos = { version = \"0.7.5\", features = [\"rocket\"] }";
+        let code_cargo_frameworks = highlight!("toml", "# Axum\ntidos = { version = \"0.7.5\", features = [\"axum\"] }\n\n# Actix-web\ntidos = { version = \"0.7.5\", features = [\"actix-web\"] }\n\n# Warp\ntidos = { version = \"0.7.5\", features = [\"warp\"] }\n\n# Rocket (unchanged)\ntidos = { version = \"0.7.5\", features = [\"rocket\"] }");
+        let code_native_element = highlight!("rust", "use tidos::native_element;\n\n#[native_element]\npub struct MyCounter {\n    pub initial: i32,\n    pub step: i32,\n}\n\n// Renders: <my-counter initial=\"0\" step=\"1\"></my-counter>\n// and injects: <script src=\"/dist/my-counter.js\"></script>");
+        let code_cargo = highlight!("toml", "# Feature flag for your framework of choice\ntidos = { version = \"0.7.5\", features = [\"rocket\"] }");
 
         view! {
             <div class="article-content">
@@ -48,12 +49,12 @@ impl Component for ArticleContent {
 
                 <h2>{"Axum, Actix-web, and Warp"}</h2>
                 <p>{"Tidos now ships feature flags for three additional HTTP frameworks. Add the flag for your framework of choice to Cargo.toml:"}</p>
-                <CodeBlock code={code_cargo_frameworks.to_string()} />
+                <CodeBlock code={code_cargo_frameworks} />
                 <p>{"Each flag implements the appropriate response trait so a Page can be returned directly from a route handler. See the Getting Started page for full examples."}</p>
 
                 <h2>{"The #[native_element] macro"}</h2>
                 <p>{"Tidos now ships a #[native_element] attribute macro that generates a Component implementation for any struct that wraps a custom HTML element. It injects the required script tag, renders the kebab-case element name, and maps every struct field to an HTML attribute:"}</p>
-                <CodeBlock code={code_native_element.to_string()} />
+                <CodeBlock code={code_native_element} />
                 <p>{"This bridges the gap between Tidos SSR and client-side web components: define the component once in Rust and let the browser hydrate it."}</p>
 
                 <h2>{"JavaScript framework examples"}</h2>
@@ -69,7 +70,7 @@ impl Component for ArticleContent {
 
                 <h2>{"Upgrading"}</h2>
                 <p>{"No breaking changes. Switch the feature flag in your Cargo.toml to the framework you use:"}</p>
-                <CodeBlock code={code_cargo.to_string()} />
+                <CodeBlock code={code_cargo} />
             </div>
         }
     }
@@ -2,7 +2,7 @@ use crate::components::code_block::CodeBlock;
 use crate::components::nav_bar::NavBarDocs;
 use crate::components::news_layout::NewsLayout;
 use rocket::get;
-use tidos::{head, page, view, Component, Page};
+use tidos::{head, highlight, page, view, Component, Page, Props};
 
 #[get("/news/v0-8-0-rc1")]
 pub fn news_v0_8_0_rc1() -> Page {
@@ -26,13 +26,14 @@ pub fn news_v0_8_0_rc1() -> Page {
     page
 }
 
+#[derive(Props)]
 struct ArticleContent;
 
 impl Component for ArticleContent {
     fn to_render(&self, page: &mut Page) {
-        let code_breaking = "// Before (no longer compiles)\nview! { <p>Hello world</p> }\n\n// After\nview! { <p>{\"Hello world\"}</p> }";
-        let code_expr = "view! {\n    <p>{\"Hello {}\", name}</p>   // formatted\n    <p>{name}</p>               // expression\n}";
-        let code_cargo = "[dependencies]\ntidos = \"0.8.0-rc.1\"";
+        let code_breaking = highlight!("rust", "// Before (no longer compiles)\nview! { <p>Hello world</p> }\n\n// After\nview! { <p>{\"Hello world\"}</p> }");
+        let code_expr = highlight!("rust", "view! {\n    <p>{\"Hello {}\", name}</p>   // formatted\n    <p>{name}</p>               // expression\n}");
+        let code_cargo = highlight!("toml", "[dependencies]\ntidos = \"0.8.0-rc.1\"");
 
         view! {
             <div class="article-content">
@@ -53,14 +54,14 @@ impl Component for ArticleContent {
 
                 <h2>{"Breaking change: text must be wrapped in curly braces"}<span class="breaking-badge">{"Breaking"}</span></h2>
                 <p>{"To enable compile-time safety and the new optimisation, all text content inside view! must now be explicitly wrapped in curly braces. Raw text between tags is no longer allowed:"}</p>
-                <CodeBlock code={code_breaking.to_string()} />
+                <CodeBlock code={code_breaking} />
                 <p>{"The macro panics at compile time if it encounters unwrapped text, so migration errors are caught immediately. The fix is always mechanical: wrap the text in double-quoted curly braces."}</p>
                 <p>{"If you have variables or expressions, keep using the expression form:"}</p>
-                <CodeBlock code={code_expr.to_string()} />
+                <CodeBlock code={code_expr} />
 
                 <h2>{"Upgrading"}</h2>
                 <p>{"Update your Cargo.toml to use the release candidate:"}</p>
-                <CodeBlock code={code_cargo.to_string()} />
+                <CodeBlock code={code_cargo} />
                 <p>{"Then search your codebase for raw text between HTML tags and wrap each occurrence in curly braces. Most editors can do this with a regex find-and-replace."}</p>
             </div>
         }
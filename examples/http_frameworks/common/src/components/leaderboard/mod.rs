@@ -1,4 +1,4 @@
-use tidos::{scoped_css, view, Component, Page, Slot};
+use tidos::{scoped_css, view, Component, Page, Props, Slot};
 
 use crate::components::player_row::PlayerRow;
 
@@ -8,6 +8,7 @@ pub struct Player {
     pub online: bool,
 }
 
+#[derive(Props)]
 pub struct Leaderboard;
 
 impl Component for Leaderboard {
@@ -59,6 +60,7 @@ impl Component for Leaderboard {
     }
 }
 
+#[derive(Props)]
 pub struct LeaderboardTable<'a> {
     pub headers: Vec<String>,
     pub body: Slot<'a>,
@@ -1,5 +1,6 @@
-use tidos::{scoped_css, view, Component, Page};
+use tidos::{scoped_css, view, Component, Page, Props};
 
+#[derive(Props)]
 pub struct PlayerRow {
     pub rank: usize,
     pub name: String,
@@ -32,7 +33,7 @@ impl Component for PlayerRow {
                     {/if}
                 </td>
                 <td>
-                    {self.score.to_string()}
+                    {self.score}
                 </td>
                 <td>
                     {#if self.score >= 9000}
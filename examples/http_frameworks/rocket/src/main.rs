@@ -12,7 +12,7 @@ pub fn index() -> Page {
     ];
 
     page! {
-        <NavBar title={String::from("🦀 Ferris Fan Club")} links={nav_links} />
+        <NavBar title="🦀 Ferris Fan Club" links={nav_links} />
         <main class={scoped_css!("./main.css")}>
             <h1>{"Welcome, Rustacean!"}</h1>
             <p>{"The premier leaderboard for crab enthusiasts worldwide."}</p>
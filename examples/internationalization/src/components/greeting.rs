@@ -1,6 +1,7 @@
-use tidos::{view, Component, Page};
+use tidos::{view, Component, Page, Props};
 use tidos::i18n::i18n;
 
+#[derive(Props)]
 pub struct Greeting;
 
 impl Component for Greeting {
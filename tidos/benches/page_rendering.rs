@@ -5,13 +5,14 @@ use stats_alloc::{Region, StatsAlloc, INSTRUMENTED_SYSTEM};
 use std::alloc::System;
 use std::hint::black_box;
 use std::sync::Once;
-use tidos::{page, view, Component, Page};
+use tidos::{page, view, Component, Page, Props};
 
 #[global_allocator]
 static GLOBAL: &StatsAlloc<System> = &INSTRUMENTED_SYSTEM;
 
 // ---- Components ----
 
+#[derive(Props)]
 struct ListItem {
 	pub index: usize,
 	pub label: String,
@@ -21,7 +22,7 @@ impl Component for ListItem {
 	fn to_render(&self, page: &mut Page) {
 		view! {
 			<li>
-				<span>{self.index.to_string()}</span>
+				<span>{self.index}</span>
 				<span>{&self.label}</span>
 			</li>
 		};
@@ -115,7 +116,7 @@ fn print_memory_stats() {
 
 		for &n in &[10_usize, 100, 1000] {
 			let labels = make_labels(n);
-			let reg = Region::new(&GLOBAL);
+			let reg = Region::new(GLOBAL);
 			let page = render_flat_list(&labels);
 			let stats = reg.change();
 			eprintln!(
@@ -128,7 +129,7 @@ fn print_memory_stats() {
 			);
 		}
 
-		let reg = Region::new(&GLOBAL);
+		let reg = Region::new(GLOBAL);
 		let page = render_static();
 		let stats = reg.change();
 		eprintln!(
@@ -142,7 +143,7 @@ fn print_memory_stats() {
 
 		for &n in &[10_usize, 100, 1000] {
 			let items = make_list_items(n);
-			let reg = Region::new(&GLOBAL);
+			let reg = Region::new(GLOBAL);
 			let page = render_borrowed_list(&items);
 			let stats = reg.change();
 			eprintln!(
@@ -156,7 +157,7 @@ fn print_memory_stats() {
 		}
 
 		for &n in &[10_usize, 100, 1000] {
-			let reg = Region::new(&GLOBAL);
+			let reg = Region::new(GLOBAL);
 			let page = render_range_if(n);
 			let stats = reg.change();
 			eprintln!(
@@ -0,0 +1,19 @@
+use actix_web::http::header;
+use actix_web::{web, HttpRequest, HttpResponse, Resource};
+
+/// A resource serving every asset under `/assets/`.
+pub fn service() -> Resource {
+	web::resource("/assets/{file}").route(web::get().to(handler))
+}
+
+/// Responds with the asset at the request's path, for mounting at
+/// `/assets/{file}` by hand.
+pub async fn handler(request: HttpRequest) -> HttpResponse {
+	match super::get(request.path()) {
+		Some(asset) => HttpResponse::Ok()
+			.content_type(asset.content_type)
+			.insert_header((header::CACHE_CONTROL, super::CACHE_CONTROL))
+			.body(asset.bytes),
+		None => HttpResponse::NotFound().finish(),
+	}
+}
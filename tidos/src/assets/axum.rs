@@ -0,0 +1,29 @@
+use axum::extract::Path;
+use axum::http::{header, StatusCode};
+use axum::response::{IntoResponse, Response};
+use axum::routing::get;
+use axum::Router;
+
+/// A router serving every asset under `/assets/`.
+pub fn router<S>() -> Router<S>
+where
+	S: Clone + Send + Sync + 'static,
+{
+	Router::new().route("/assets/{file}", get(handler))
+}
+
+/// Responds with the asset named `file`, for mounting at
+/// `/assets/{file}` by hand.
+pub async fn handler(Path(file): Path<String>) -> Response {
+	match super::get(&format!("/assets/{file}")) {
+		Some(asset) => (
+			[
+				(header::CONTENT_TYPE, asset.content_type),
+				(header::CACHE_CONTROL, super::CACHE_CONTROL),
+			],
+			asset.bytes,
+		)
+			.into_response(),
+		None => StatusCode::NOT_FOUND.into_response(),
+	}
+}
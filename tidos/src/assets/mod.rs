@@ -0,0 +1,104 @@
+//! Static files embedded with [`asset!`](macro@crate::asset), served at
+//! fingerprinted URLs.
+//!
+//! Each `asset!` call registers its file here while compiling. The URL
+//! contains a hash of the file's contents, e.g. `/assets/logo.3fa1c2d0.svg`,
+//! so a changed file gets a new URL and the responses can be cached forever.
+//!
+//! Serve them with the integration of your web framework:
+//!
+//! ```rust,ignore
+//! // axum
+//! let app = Router::new().merge(tidos::assets::axum::router());
+//! // Rocket
+//! rocket::build().mount("/", tidos::assets::rocket::routes());
+//! // Actix Web
+//! App::new().service(tidos::assets::actix_web::service());
+//! // warp
+//! let routes = tidos::assets::warp::filter().or(pages);
+//! ```
+//!
+//! [`manifest`] lists every asset by its source path, e.g. to upload them to
+//! a CDN, and [`manifest_json`] writes it out.
+
+use std::collections::{BTreeMap, HashMap};
+use std::sync::LazyLock;
+
+#[cfg(feature = "actix-web")]
+pub mod actix_web;
+#[cfg(feature = "axum")]
+pub mod axum;
+#[cfg(feature = "rocket")]
+pub mod rocket;
+#[cfg(feature = "warp")]
+pub mod warp;
+
+/// The `Cache-Control` header for assets, whose URLs change with their
+/// contents.
+pub const CACHE_CONTROL: &str = "public, max-age=31536000, immutable";
+
+/// A file registered by `asset!`.
+#[derive(Debug)]
+pub struct Asset {
+	/// The file's path relative to the crate that embeds it, e.g.
+	/// `public/logo.svg`.
+	pub source: &'static str,
+	/// Where the file is served, e.g. `/assets/logo.3fa1c2d0.svg`.
+	pub url: &'static str,
+	/// The `Content-Type` guessed from the file's extension.
+	pub content_type: &'static str,
+	pub bytes: &'static [u8],
+}
+
+inventory::collect!(Asset);
+
+/// Every asset, by URL.
+static ASSETS: LazyLock<HashMap<&'static str, &'static Asset>> = LazyLock::new(|| {
+	inventory::iter::<Asset>
+		.into_iter()
+		.map(|asset| (asset.url, asset))
+		.collect()
+});
+
+/// The asset served at `url`, e.g. `/assets/logo.3fa1c2d0.svg`.
+pub fn get(url: &str) -> Option<&'static Asset> {
+	ASSETS.get(url).copied()
+}
+
+/// Every asset's URL by its source path, sorted.
+pub fn manifest() -> BTreeMap<&'static str, &'static str> {
+	ASSETS
+		.values()
+		.map(|asset| (asset.source, asset.url))
+		.collect()
+}
+
+/// The [`manifest`] as a JSON object, e.g.
+/// `{"public/logo.svg":"/assets/logo.3fa1c2d0.svg"}`.
+pub fn manifest_json() -> String {
+	let mut json = String::from("{");
+	for (index, (source, url)) in manifest().into_iter().enumerate() {
+		if index > 0 {
+			json.push(',');
+		}
+		json.push_str(&json_string(source));
+		json.push(':');
+		json.push_str(&json_string(url));
+	}
+	json.push('}');
+	json
+}
+
+fn json_string(value: &str) -> String {
+	let mut json = String::from("\"");
+	for c in value.chars() {
+		match c {
+			'"' => json.push_str("\\\""),
+			'\\' => json.push_str("\\\\"),
+			c if c.is_control() => json.push_str(&format!("\\u{:04x}", c as u32)),
+			c => json.push(c),
+		}
+	}
+	json.push('"');
+	json
+}
@@ -0,0 +1,27 @@
+use rocket::http::{Method, Status};
+use rocket::route::{Handler, Outcome, Route};
+use rocket::{Data, Request, Response};
+use std::io::Cursor;
+
+/// The route serving every asset under `/assets/`, to mount at `/`.
+pub fn routes() -> Vec<Route> {
+	vec![Route::new(Method::Get, "/assets/<file>", Assets)]
+}
+
+#[derive(Clone)]
+struct Assets;
+
+#[rocket::async_trait]
+impl Handler for Assets {
+	async fn handle<'r>(&self, request: &'r Request<'_>, data: Data<'r>) -> Outcome<'r> {
+		let Some(asset) = super::get(request.uri().path().as_str()) else {
+			return Outcome::forward(data, Status::NotFound);
+		};
+		let response = Response::build()
+			.raw_header("Content-Type", asset.content_type)
+			.raw_header("Cache-Control", super::CACHE_CONTROL)
+			.sized_body(asset.bytes.len(), Cursor::new(asset.bytes))
+			.finalize();
+		Outcome::Success(response)
+	}
+}
@@ -0,0 +1,19 @@
+use warp::http::header;
+use warp::{Filter, Rejection, Reply};
+
+/// A filter serving every asset under `/assets/`.
+pub fn filter() -> impl Filter<Extract = (impl Reply,), Error = Rejection> + Clone {
+	warp::get()
+		.and(warp::path!("assets" / String))
+		.and_then(|file: String| async move {
+			let asset =
+				super::get(&format!("/assets/{file}")).ok_or_else(warp::reject::not_found)?;
+			let reply =
+				warp::reply::with_header(asset.bytes, header::CONTENT_TYPE, asset.content_type);
+			Ok::<_, Rejection>(warp::reply::with_header(
+				reply,
+				header::CACHE_CONTROL,
+				super::CACHE_CONTROL,
+			))
+		})
+}
@@ -1,4 +1,5 @@
 pub(crate) use crate::page::Page;
+use crate::Markup;
 
 /// Type alias for a slot closure — a boxed function that pushes rendered HTML
 /// into a [`Page`] when called.
@@ -17,11 +18,20 @@ pub(crate) use crate::page::Page;
 ///   slot, or a single slot alongside other props/attributes (an unnamed
 ///   tuple field can't coexist with named fields in the same tag).
 ///
+/// When the slot content needs data from the component, use a [`SlotWith`]
+/// field instead.
+///
+/// A slot the parent may leave out is declared as `Option<Slot<'render>>`.
+/// `@slot{...}` renders nothing for `None`, or renders fallback markup when
+/// written as `@slot{self.footer}{:else} … {/slot}`. Like any `Option` prop,
+/// the parent can leave it out (see [`Props`]).
+///
 /// # Example — unnamed slot
 ///
 /// ```rust,no_run
-/// use tidos::{view, Component, Page, Slot};
+/// use tidos::{view, Component, Page, Props, Slot};
 ///
+/// #[derive(Props)]
 /// pub struct Card<'a>(pub Slot<'a>);
 ///
 /// impl Component for Card<'_> {
@@ -45,8 +55,9 @@ pub(crate) use crate::page::Page;
 /// # Example — named slots
 ///
 /// ```rust,no_run
-/// use tidos::{view, Component, Page, Slot};
+/// use tidos::{view, Component, Page, Props, Slot};
 ///
+/// #[derive(Props)]
 /// pub struct Card<'a> {
 ///     pub header: Slot<'a>,
 ///     pub body: Slot<'a>,
@@ -71,13 +82,84 @@ pub(crate) use crate::page::Page;
 /// //     </Card>
 /// // }
 /// ```
+///
+/// # Example — optional slot with fallback
+///
+/// ```rust,no_run
+/// use tidos::{view, Component, Page, Props, Slot};
+///
+/// #[derive(Props)]
+/// pub struct Panel<'a> {
+///     pub title: String,
+///     pub footer: Option<Slot<'a>>,
+/// }
+///
+/// impl Component for Panel<'_> {
+///     fn to_render(&self, page: &mut Page) {
+///         view! {
+///             <section>
+///                 <h2>{&self.title}</h2>
+///                 <footer>@slot{self.footer}{:else}<p>{"Default footer"}</p>{/slot}</footer>
+///             </section>
+///         }
+///     }
+/// }
+///
+/// // Parent side — the footer may be filled or left out:
+/// // view! {
+/// //     <Panel title="News">
+/// //         {#slot:footer}<a href="/news">{"All news"}</a>{/slot}
+/// //     </Panel>
+/// //     <Panel title="Events" />
+/// // }
+/// ```
 pub type Slot<'render> = Box<dyn Fn(&mut Page) + 'render>;
 
+/// Type alias for a scoped slot closure — a [`Slot`] that also receives a
+/// value from the component rendering it.
+///
+/// Use this when a component owns the data but the parent decides the
+/// markup, such as a generic table or list. Inside `to_render`, pass the value
+/// with `@slot{self.field, value}`. On the parent side, a
+/// `{#slot:name |binding|} … {/slot}` block names the argument it receives.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use tidos::{view, Component, Page, Props, SlotWith};
+///
+/// #[derive(Props)]
+/// pub struct List<'a, T> {
+///     pub items: &'a [T],
+///     pub row: SlotWith<'a, T>,
+/// }
+///
+/// impl<T> Component for List<'_, T> {
+///     fn to_render(&self, page: &mut Page) {
+///         view! {
+///             <ul>
+///                 {#for item in self.items}
+///                     <li>@slot{self.row, item}</li>
+///                 {/for}
+///             </ul>
+///         }
+///     }
+/// }
+///
+/// // Parent side:
+/// // view! {
+/// //     <List items={&names}>
+/// //         {#slot:row |name|}<strong>{name}</strong>{/slot}
+/// //     </List>
+/// // }
+/// ```
+pub type SlotWith<'render, T> = Box<dyn Fn(&mut Page, &T) + 'render>;
+
 /// A reusable UI component.
 ///
 /// Implement this trait on any struct to make it renderable inside
 /// [`view!`](macro@crate::view), [`page!`](macro@crate::page), or other
-/// components. The [`to_render`](Component::to_render) method receives a
+/// components. To use it as a tag in a template, also derive [`Props`]. The [`to_render`](Component::to_render) method receives a
 /// mutable reference to the current [`Page`] so that child components can
 /// inject CSS or `<head>` elements.
 ///
@@ -100,6 +182,44 @@ pub type Slot<'render> = Box<dyn Fn(&mut Page) + 'render>;
 ///     }
 /// }
 /// ```
+///
+/// # Containers of components
+///
+/// References, boxes (including `Box<dyn Component>`), `Option`s, `Vec`s,
+/// slices and tuples of components are components themselves, so they can be
+/// rendered with `@render{expr}` without a wrapper:
+///
+/// | Type | Renders |
+/// |---|---|
+/// | `&T`, `Box<T>` | The component it points to |
+/// | `Option<T>` | The component when `Some`, nothing when `None` |
+/// | `Vec<T>`, `[T]` | Every component, in order |
+/// | `(A, B, …)` | Every component, in order |
+///
+/// ```rust,no_run
+/// use tidos::{view, Component, Page};
+///
+/// pub struct Sidebar {
+///     pub widgets: Vec<Box<dyn Component>>,
+///     pub banner: Option<Box<dyn Component>>,
+/// }
+///
+/// impl Component for Sidebar {
+///     fn to_render(&self, page: &mut Page) {
+///         view! {
+///             <aside>
+///                 @render{self.banner}
+///                 @render{self.widgets}
+///             </aside>
+///         }
+///     }
+/// }
+/// ```
+#[diagnostic::on_unimplemented(
+	message = "`{Self}` is not a component",
+	label = "`@render{{…}}` needs a type implementing `tidos::Component`",
+	note = "snippets are rendered by calling them, e.g. `@render{{row(&user)}}`"
+)]
 pub trait Component {
 	/// Render the component to an HTML string.
 	///
@@ -108,4 +228,188 @@ pub trait Component {
 	/// [`Page::add_elements_to_head`] or forward it to child components via
 	/// their own `to_render(page)` calls.
 	fn to_render(&self, page: &mut Page);
+
+	/// Render the component into [`Markup`] instead of adding it to the page,
+	/// e.g. to pass it to another component as a prop.
+	///
+	/// See [`Page::capture`].
+	fn to_markup(&self, page: &mut Page) -> Markup {
+		page.capture(|page| self.to_render(page))
+	}
+}
+
+impl<T: Component + ?Sized> Component for &T {
+	#[inline]
+	fn to_render(&self, page: &mut Page) {
+		(**self).to_render(page);
+	}
+}
+
+impl<T: Component + ?Sized> Component for Box<T> {
+	#[inline]
+	fn to_render(&self, page: &mut Page) {
+		(**self).to_render(page);
+	}
+}
+
+impl<T: Component> Component for Option<T> {
+	#[inline]
+	fn to_render(&self, page: &mut Page) {
+		if let Some(component) = self {
+			component.to_render(page);
+		}
+	}
+}
+
+impl<T: Component> Component for [T] {
+	fn to_render(&self, page: &mut Page) {
+		for component in self {
+			component.to_render(page);
+		}
+	}
+}
+
+impl<T: Component> Component for Vec<T> {
+	#[inline]
+	fn to_render(&self, page: &mut Page) {
+		self.as_slice().to_render(page);
+	}
+}
+
+macro_rules! impl_component_for_tuple {
+	($($name:ident)*) => {
+		impl<$($name: Component),*> Component for ($($name,)*) {
+			#[allow(non_snake_case, unused_variables)]
+			fn to_render(&self, page: &mut Page) {
+				let ($($name,)*) = self;
+				$($name.to_render(page);)*
+			}
+		}
+	};
+}
+
+impl_component_for_tuple!(A);
+impl_component_for_tuple!(A B);
+impl_component_for_tuple!(A B C);
+impl_component_for_tuple!(A B C D);
+impl_component_for_tuple!(A B C D E);
+impl_component_for_tuple!(A B C D E F);
+impl_component_for_tuple!(A B C D E F G);
+impl_component_for_tuple!(A B C D E F G H);
+
+/// Types that [`view!`](macro@crate::view) can construct from the attributes
+/// and slots of a component tag.
+///
+/// Implement it with `#[derive(Props)]`, which generates a typestate builder:
+/// every field gets a setter named after it, and `build` only compiles once
+/// every required field has been set. A missing prop is reported at the
+/// component tag, e.g. ``missing required prop `title` on `<Card>` ``.
+///
+/// | Field | Behaviour when left out |
+/// |---|---|
+/// | `title: String` | Compile error |
+/// | `subtitle: Option<String>` | `None` |
+/// | `#[prop(optional)] count: usize` | `Default::default()` |
+/// | `#[prop(default = 3)] level: u8` | The given expression |
+///
+/// `#[prop(into)]` makes the setter accept anything that converts into the
+/// field type. `view!` converts attribute values with [`IntoProp`] either way,
+/// except for numbers: a `u32` prop takes a `u32`, so that `count={5}` infers
+/// the literal's type. Widen other numbers yourself, e.g.
+/// `count={u32::from(small)}`.
+///
+/// Tuple structs get setters named `_0`, `_1`, …; the children of a component
+/// tag without `{#slot:…}` blocks are passed to `_0`.
+///
+/// A tag with the `..` flag, e.g. `<Button label="Save" .. />`, is
+/// built with struct update syntax and needs [`Default`] instead of `Props`.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use tidos::{view, Component, Page, Props, Slot};
+///
+/// #[derive(Props)]
+/// pub struct Card<'a> {
+///     pub title: String,
+///     #[prop(default = 2)]
+///     pub level: u8,
+///     pub footer: Option<Slot<'a>>,
+/// }
+///
+/// impl Component for Card<'_> {
+///     fn to_render(&self, page: &mut Page) {
+///         view! {
+///             <section data-level={self.level.to_string()}>
+///                 <h2>{&self.title}</h2>
+///                 @slot{self.footer}
+///             </section>
+///         }
+///     }
+/// }
+///
+/// // Parent side — `level` and `footer` may be left out:
+/// // view! { <Card title="News" /> }
+/// ```
+pub trait Props {
+	/// The builder returned by [`builder`](Props::builder), with no props set.
+	type Builder;
+
+	/// Start building the props.
+	fn builder() -> Self::Builder;
+}
+
+/// Conversion applied by [`view!`](macro@crate::view) to every attribute
+/// value passed to a component prop.
+///
+/// It is implemented for every type that implements [`Into`] for the prop's
+/// type, so `title="News"` fills a `String` prop, a `&str` fills a
+/// `Cow<str>`, and a plain value fills an `Option` prop:
+///
+/// ```rust,no_run
+/// use tidos::{view, Component, Page, Props};
+///
+/// #[derive(Props)]
+/// pub struct Card {
+///     pub title: String,
+///     pub subtitle: Option<String>,
+///     pub level: u64,
+/// }
+///
+/// impl Component for Card {
+///     fn to_render(&self, page: &mut Page) {
+///         view! {
+///             <h2 data-level={self.level.to_string()} title={&self.subtitle}>{&self.title}</h2>
+///         }
+///     }
+/// }
+///
+/// let subtitle = String::from("Today");
+/// let mut page = Page::new();
+/// let page = &mut page;
+/// view! {
+///     <Card title="News" subtitle={subtitle} level={2} />
+/// }
+/// ```
+///
+/// `#[derive(Props)]` decides from each field's type whether to convert.
+/// Values for these fields are passed unconverted, because they rely on the
+/// field's exact type:
+///
+/// - references, so `items={&users}` can still coerce from `&Vec<User>` to
+///   `&[User]`;
+/// - `Box`, `Rc`, `Arc` and slots, so a boxed closure can still coerce to a
+///   [`Slot`];
+/// - types mentioning the component's type parameters, which are inferred
+///   from the value.
+pub trait IntoProp<T> {
+	/// Convert the attribute value into the prop's type.
+	fn into_prop(self) -> T;
+}
+
+impl<T, U: Into<T>> IntoProp<T> for U {
+	#[inline]
+	fn into_prop(self) -> T {
+		self.into()
+	}
 }
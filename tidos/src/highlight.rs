@@ -0,0 +1,42 @@
+//! Styles for code highlighted with [`highlight!`](crate::highlight!).
+//!
+//! The macro only emits class names, so the colours live in a stylesheet.
+//! Add [`STYLESHEET`] to the page once, or write your own theme for the same
+//! classes:
+//!
+//! | Class | Tokens |
+//! |---|---|
+//! | `hl-comment` | Comments |
+//! | `hl-keyword` | Keywords, `self` |
+//! | `hl-string` | String literals |
+//! | `hl-escape` | Escape sequences inside strings |
+//! | `hl-number` | Numbers |
+//! | `hl-constant` | `true`, `false` and other constants |
+//! | `hl-operator` | Operators |
+//! | `hl-type` | Type, section and class names |
+//! | `hl-function` | Function names |
+//! | `hl-macro` | Macro invocations |
+//! | `hl-tag` | HTML tag names |
+//! | `hl-attribute` | HTML attributes and TOML keys |
+//! | `hl-variable` | Variables and parameters |
+//!
+//! ```rust,no_run
+//! use tidos::highlight::STYLESHEET;
+//! use tidos::{head, highlight, view, Component, Page};
+//!
+//! pub struct Example;
+//!
+//! impl Component for Example {
+//!     fn to_render(&self, page: &mut Page) {
+//!         head! {
+//!             <style>@html{STYLESHEET}</style>
+//!         }
+//!         view! {
+//!             <pre><code>{highlight!("css", file = "./highlight.css")}</code></pre>
+//!         }
+//!     }
+//! }
+//! ```
+
+/// The default dark theme for highlighted code.
+pub const STYLESHEET: &str = include_str!("highlight.css");
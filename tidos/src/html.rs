@@ -0,0 +1,224 @@
+//! Allow-list sanitizing of untrusted HTML, such as user comments or CMS
+//! content.
+//!
+//! Requires the `sanitizer` feature.
+//!
+//! [`clean`] parses the HTML and keeps only the tags, attributes and URL
+//! schemes that a [`Policy`] allows, then returns the result as [`Markup`]
+//! ready to be rendered with `{expr}`. Everything else is removed:
+//! `<script>` and `<style>` elements together with their content, event
+//! handlers such as `onclick`, `javascript:` URLs and comments. Tags that are
+//! not allowed are unwrapped, so their text stays.
+//!
+//! In a template, `@sanitized_html{expr}` cleans `expr` with the default
+//! policy:
+//!
+//! ```rust,no_run
+//! use tidos::{view, Page};
+//!
+//! let comment = String::from(r#"<p onclick="steal()">Nice <b>post</b>!</p><script>steal()</script>"#);
+//! let mut page = Page::new();
+//! let page = &mut page;
+//!
+//! view! { <div class="comment">@sanitized_html{comment}</div> }
+//! // <div class="comment"><p>Nice <b>post</b>!</p></div>
+//! ```
+
+use crate::Markup;
+use std::sync::LazyLock;
+
+/// Tags allowed by [`Policy::rich_text`].
+const RICH_TEXT_TAGS: &[&str] = &[
+	"a",
+	"abbr",
+	"b",
+	"blockquote",
+	"br",
+	"caption",
+	"cite",
+	"code",
+	"dd",
+	"del",
+	"dl",
+	"dt",
+	"em",
+	"figcaption",
+	"figure",
+	"h1",
+	"h2",
+	"h3",
+	"h4",
+	"h5",
+	"h6",
+	"hr",
+	"i",
+	"img",
+	"ins",
+	"kbd",
+	"li",
+	"mark",
+	"ol",
+	"p",
+	"pre",
+	"q",
+	"s",
+	"small",
+	"span",
+	"strong",
+	"sub",
+	"sup",
+	"table",
+	"tbody",
+	"td",
+	"tfoot",
+	"th",
+	"thead",
+	"tr",
+	"u",
+	"ul",
+];
+
+/// Attributes allowed by [`Policy::rich_text`], per tag.
+const RICH_TEXT_ATTRIBUTES: &[(&str, &[&str])] = &[
+	("a", &["href", "title"]),
+	("abbr", &["title"]),
+	("blockquote", &["cite"]),
+	("img", &["src", "alt", "title", "width", "height"]),
+	("ol", &["start", "reversed"]),
+	("q", &["cite"]),
+	("td", &["colspan", "rowspan"]),
+	("th", &["colspan", "rowspan", "scope"]),
+];
+
+/// URL schemes allowed by [`Policy::rich_text`]. URLs without a scheme, like
+/// `/about` or `#top`, are always allowed.
+const RICH_TEXT_URL_SCHEMES: &[&str] = &["http", "https", "mailto"];
+
+static RICH_TEXT: LazyLock<Policy> = LazyLock::new(Policy::rich_text);
+
+/// Clean `html` with the default [`Policy::rich_text`] policy.
+///
+/// ```rust
+/// let markup = tidos::html::clean(r#"<a href="javascript:alert(1)">Hi</a> <em>there</em>"#);
+/// assert_eq!(markup.as_str(), r#"<a rel="noopener noreferrer nofollow">Hi</a> <em>there</em>"#);
+/// ```
+pub fn clean(html: impl AsRef<str>) -> Markup {
+	RICH_TEXT.clean(html)
+}
+
+/// An allow-list of the tags, attributes and URL schemes that survive
+/// [`Policy::clean`].
+///
+/// Start from [`Policy::rich_text`] or [`Policy::text_only`] and adjust it.
+/// Build a policy once, e.g. in a `static` [`LazyLock`], rather than on
+/// every render.
+///
+/// ```rust
+/// use std::sync::LazyLock;
+/// use tidos::html::Policy;
+///
+/// static COMMENTS: LazyLock<Policy> = LazyLock::new(|| {
+///     Policy::rich_text()
+///         .deny_tags(["img", "table"])
+///         .allow_attributes("code", ["class"])
+/// });
+///
+/// let markup = COMMENTS.clean(r#"<img src="x.png"><code class="rust">fn</code>"#);
+/// assert_eq!(markup.as_str(), r#"<code class="rust">fn</code>"#);
+/// ```
+#[derive(Debug)]
+pub struct Policy {
+	builder: ammonia::Builder<'static>,
+}
+
+impl Policy {
+	/// Formatting, headings, lists, quotes, code, tables, links and images,
+	/// with `http`, `https` and `mailto` URLs. Links get
+	/// `rel="noopener noreferrer nofollow"`.
+	pub fn rich_text() -> Policy {
+		let mut builder = ammonia::Builder::default();
+		builder
+			.tags(RICH_TEXT_TAGS.iter().copied().collect())
+			.tag_attributes(
+				RICH_TEXT_ATTRIBUTES
+					.iter()
+					.map(|(tag, attributes)| (*tag, attributes.iter().copied().collect()))
+					.collect(),
+			)
+			.generic_attributes(["lang", "title"].into_iter().collect())
+			.url_schemes(RICH_TEXT_URL_SCHEMES.iter().copied().collect())
+			.link_rel(Some("noopener noreferrer nofollow"));
+		Policy { builder }
+	}
+
+	/// No tags at all: markup is stripped and only its text is kept.
+	pub fn text_only() -> Policy {
+		let mut builder = ammonia::Builder::empty();
+		builder.generic_attributes(Default::default());
+		Policy { builder }
+	}
+
+	/// Also allow `tags`.
+	pub fn allow_tags(mut self, tags: impl IntoIterator<Item = &'static str>) -> Policy {
+		self.builder.add_tags(tags);
+		self
+	}
+
+	/// Remove `tags`, keeping their content.
+	pub fn deny_tags(mut self, tags: impl IntoIterator<Item = &'static str>) -> Policy {
+		self.builder.rm_tags(tags);
+		self
+	}
+
+	/// Also allow `attributes` on `tag`.
+	///
+	/// Never allow event handlers, like `onclick`, or `style`: both can run
+	/// or load arbitrary content.
+	pub fn allow_attributes(
+		mut self,
+		tag: &'static str,
+		attributes: impl IntoIterator<Item = &'static str>,
+	) -> Policy {
+		self.builder.add_tag_attributes(tag, attributes);
+		self
+	}
+
+	/// Also allow `attributes` on every allowed tag.
+	pub fn allow_generic_attributes(
+		mut self,
+		attributes: impl IntoIterator<Item = &'static str>,
+	) -> Policy {
+		self.builder.add_generic_attributes(attributes);
+		self
+	}
+
+	/// Also allow URLs with these schemes, e.g. `tel`.
+	pub fn allow_url_schemes(mut self, schemes: impl IntoIterator<Item = &'static str>) -> Policy {
+		self.builder.add_url_schemes(schemes);
+		self
+	}
+
+	/// Drop URLs with these schemes.
+	pub fn deny_url_schemes(mut self, schemes: impl IntoIterator<Item = &'static str>) -> Policy {
+		self.builder.rm_url_schemes(schemes);
+		self
+	}
+
+	/// The `rel` attribute added to every link, or `None` to add nothing.
+	pub fn link_rel(mut self, rel: Option<&'static str>) -> Policy {
+		self.builder.link_rel(rel);
+		self
+	}
+
+	/// Clean `html`, keeping only what this policy allows.
+	pub fn clean(&self, html: impl AsRef<str>) -> Markup {
+		Markup::trusted(self.builder.clean(html.as_ref()).to_string())
+	}
+}
+
+impl Default for Policy {
+	/// The [`Policy::rich_text`] policy.
+	fn default() -> Policy {
+		Policy::rich_text()
+	}
+}
@@ -1,4 +1,269 @@
+use crate::{AttributeValue, Page, PushIntoPage, Slot, SlotWith, ToHtml};
 use std::borrow::Cow;
+use std::fmt;
+
+#[cfg(feature = "extract-css")]
+pub use crate::styles::ScopedStyle;
+#[cfg(any(feature = "assets", feature = "extract-css"))]
+pub use inventory;
+
+/// Box a slot closure generated by `{#slot:name}` (or by the children of a
+/// component with an unnamed slot) into the type of the component's field.
+///
+/// The field may be a [`Slot`] or an `Option<Slot>`.
+#[inline]
+pub fn slot<'render, S, F>(slot: F) -> S
+where
+	S: FromSlot<'render, F>,
+{
+	S::from_slot(slot)
+}
+
+/// Field types that [`slot`] can fill with a slot closure.
+pub trait FromSlot<'render, F> {
+	fn from_slot(slot: F) -> Self;
+}
+
+impl<'render, F: Fn(&mut Page) + 'render> FromSlot<'render, F> for Slot<'render> {
+	#[inline]
+	fn from_slot(slot: F) -> Self {
+		Box::new(slot)
+	}
+}
+
+impl<'render, F: Fn(&mut Page) + 'render> FromSlot<'render, F> for Option<Slot<'render>> {
+	#[inline]
+	fn from_slot(slot: F) -> Self {
+		Some(Box::new(slot))
+	}
+}
+
+/// Dispatch trait used by `@slot{...}` to render a [`Slot`], any other
+/// `Fn(&mut Page)`, or an `Option` of one, which renders nothing when `None`.
+pub trait RenderSlot {
+	fn render_slot(&self, page: &mut Page);
+}
+
+impl<F: Fn(&mut Page) + ?Sized> RenderSlot for F {
+	#[inline]
+	fn render_slot(&self, page: &mut Page) {
+		self(page);
+	}
+}
+
+impl<F: Fn(&mut Page)> RenderSlot for Option<F> {
+	#[inline]
+	fn render_slot(&self, page: &mut Page) {
+		if let Some(slot) = self {
+			slot(page);
+		}
+	}
+}
+
+/// Box a scoped slot closure generated by `{#slot:name |item|}`.
+///
+/// Boxing through a function with an `Fn` bound lets the compiler infer the
+/// closure's argument types from the component's [`SlotWith`] field.
+#[inline]
+pub fn slot_with<'render, T, F>(slot: F) -> SlotWith<'render, T>
+where
+	F: Fn(&mut Page, &T) + 'render,
+{
+	Box::new(slot)
+}
+
+/// State of an optional prop in a builder generated by `#[derive(Props)]`:
+/// `()` while unset, `(T,)` once set.
+pub trait OptionalProp<T> {
+	fn into_prop_or_else(self, default: impl FnOnce() -> T) -> T;
+}
+
+impl<T> OptionalProp<T> for () {
+	#[inline]
+	fn into_prop_or_else(self, default: impl FnOnce() -> T) -> T {
+		default()
+	}
+}
+
+impl<T> OptionalProp<T> for (T,) {
+	#[inline]
+	fn into_prop_or_else(self, _default: impl FnOnce() -> T) -> T {
+		self.0
+	}
+}
+
+/// Values for an `Option<T>` prop whose `T` is a number: the number itself
+/// or an `Option`. Unlike [`IntoProp`](crate::IntoProp), this keeps the type
+/// of a number literal inferable, so `level={2}` fills an `Option<u64>`.
+pub trait IntoOptionProp<T> {
+	fn into_option_prop(self) -> T;
+}
+
+impl<T> IntoOptionProp<Option<T>> for T {
+	#[inline]
+	fn into_option_prop(self) -> Option<T> {
+		Some(self)
+	}
+}
+
+impl<T> IntoOptionProp<Option<T>> for Option<T> {
+	#[inline]
+	fn into_option_prop(self) -> Option<T> {
+		self
+	}
+}
+
+/// Wrap the value of a `{expr}` text block, so `combine!` renders it through
+/// [`ToHtml`] instead of pushing it as-is.
+///
+/// The bound is checked here rather than on the `PushIntoPage` impl, so a
+/// value that cannot be rendered is reported against `ToHtml` itself.
+#[inline]
+pub fn text<T: ToHtml + ?Sized>(value: &T) -> Text<'_, T> {
+	Text(value)
+}
+
+/// A value interpolated with `{expr}`, see [`text`].
+pub struct Text<'a, T: ?Sized>(&'a T);
+
+impl<T: ToHtml + ?Sized> PushIntoPage for Text<'_, T> {
+	#[inline]
+	fn push_into_page(self, page: &mut Page) {
+		self.0.to_html(page);
+	}
+}
+
+/// A `{expr}` text block or attribute expression, rendered through the first
+/// of these that its type implements:
+///
+/// 1. [`ToHtml`] for text, [`AttributeValue`] for attributes;
+/// 2. `AsRef<str>`, written as escaped text;
+/// 3. neither, which reports the missing `ToHtml` or `AttributeValue`.
+///
+/// `view!` picks one with autoref-based dispatch, calling the method on
+/// `&&&Interpolated(&value)`: method lookup tries the impls for
+/// `&&Interpolated`, `&Interpolated` and `Interpolated` in that order, and
+/// skips those whose bounds the value doesn't meet.
+pub struct Interpolated<'a, T: ?Sized>(pub &'a T);
+
+pub trait TextViaToHtml<'a, T: ?Sized> {
+	fn text(&self) -> Text<'a, T>;
+}
+
+impl<'a, T: ToHtml + ?Sized> TextViaToHtml<'a, T> for &&Interpolated<'a, T> {
+	#[inline]
+	fn text(&self) -> Text<'a, T> {
+		Text(self.0)
+	}
+}
+
+pub trait TextViaAsRef<'a> {
+	fn text(&self) -> Text<'a, str>;
+}
+
+impl<'a, T: AsRef<str> + ?Sized> TextViaAsRef<'a> for &Interpolated<'a, T> {
+	#[inline]
+	fn text(&self) -> Text<'a, str> {
+		Text(self.0.as_ref())
+	}
+}
+
+pub trait TextFallback<'a, T: ?Sized> {
+	fn text(&self) -> Text<'a, T>
+	where
+		T: ToHtml;
+}
+
+impl<'a, T: ?Sized> TextFallback<'a, T> for Interpolated<'a, T> {
+	#[inline]
+	fn text(&self) -> Text<'a, T>
+	where
+		T: ToHtml,
+	{
+		Text(self.0)
+	}
+}
+
+pub trait AttributeViaValue {
+	fn attribute(&self, name: &str, page: &mut Page);
+}
+
+impl<T: AttributeValue + ?Sized> AttributeViaValue for &&Interpolated<'_, T> {
+	#[inline]
+	fn attribute(&self, name: &str, page: &mut Page) {
+		self.0.push_attribute(name, page);
+	}
+}
+
+pub trait AttributeViaAsRef {
+	fn attribute(&self, name: &str, page: &mut Page);
+}
+
+impl<T: AsRef<str> + ?Sized> AttributeViaAsRef for &Interpolated<'_, T> {
+	#[inline]
+	fn attribute(&self, name: &str, page: &mut Page) {
+		self.0.as_ref().push_attribute(name, page);
+	}
+}
+
+pub trait AttributeFallback<T: ?Sized> {
+	fn attribute(&self, name: &str, page: &mut Page)
+	where
+		T: AttributeValue;
+}
+
+impl<T: ?Sized> AttributeFallback<T> for Interpolated<'_, T> {
+	#[inline]
+	fn attribute(&self, name: &str, page: &mut Page)
+	where
+		T: AttributeValue,
+	{
+		self.0.push_attribute(name, page);
+	}
+}
+
+/// Wrap a value passed to `scoped_css!` as a CSS custom property, e.g.
+/// `progress = self.pct`, so it's escaped when the `style` attribute is
+/// formatted.
+#[inline]
+pub fn css_value<T: fmt::Display + ?Sized>(value: &T) -> CssValue<'_, T> {
+	CssValue(value)
+}
+
+/// A custom property's value, see [`css_value`].
+///
+/// Letters, digits, whitespace, non-ASCII characters and `# . , % + - _` are
+/// written as-is, which covers numbers, lengths and colours. Anything else is
+/// written as a CSS escape like `\3b `, so a value can't end the declaration,
+/// add another, leave the attribute, start a comment, or call a function such
+/// as `url(…)`. Functions belong in the stylesheet, e.g.
+/// `width: calc(var(--progress) * 1%)`.
+pub struct CssValue<'a, T: ?Sized>(&'a T);
+
+impl<T: fmt::Display + ?Sized> fmt::Display for CssValue<'_, T> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		use fmt::Write;
+		write!(CssEscapeWriter(f), "{}", self.0)
+	}
+}
+
+struct CssEscapeWriter<'a, 'f>(&'a mut fmt::Formatter<'f>);
+
+impl fmt::Write for CssEscapeWriter<'_, '_> {
+	fn write_str(&mut self, s: &str) -> fmt::Result {
+		for c in s.chars() {
+			let safe = c.is_alphanumeric()
+				|| !c.is_ascii()
+				|| matches!(c, ' ' | '#' | '.' | ',' | '%' | '+' | '-' | '_');
+			if safe && !c.is_control() {
+				self.0.write_char(c)?;
+			} else {
+				write!(self.0, "\\{:x} ", c as u32)?;
+			}
+		}
+		Ok(())
+	}
+}
 
 /// Sanitize `input` for safe HTML output.
 ///
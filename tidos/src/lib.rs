@@ -1,17 +1,33 @@
 #![doc = include_str!("lib.md")]
 
+#[cfg(feature = "assets")]
+pub mod assets;
 mod components;
+#[cfg(feature = "highlight")]
+pub mod highlight;
+#[cfg(feature = "sanitizer")]
+pub mod html;
+mod loop_info;
+#[cfg(feature = "markdown")]
+pub mod markdown;
+mod markup;
 mod page;
+#[cfg(feature = "extract-css")]
+pub mod styles;
+mod to_html;
 
 #[cfg(feature = "i18n")]
 mod i18n_config;
 #[doc(hidden)]
 pub mod internals;
 
-pub use components::{Component, Slot};
+pub use components::{Component, IntoProp, Props, Slot, SlotWith};
 #[doc(hidden)]
 pub use internals::sanitize;
-pub use page::{Page, PushIntoPage};
+pub use loop_info::LoopInfo;
+pub use markup::Markup;
+pub use page::{AttributeValue, Page, PushIntoPage};
+pub use to_html::{Fmt, ToHtml};
 
 #[doc(inline)]
 pub use tidos_macro::*;
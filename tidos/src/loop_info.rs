@@ -0,0 +1,33 @@
+/// Metadata about the current iteration of a `{#for}` loop.
+///
+/// Bind it by naming it after a `;` in the loop header, e.g.
+/// `{#for item in items; info}`. The iterator is never collected: `last` is
+/// determined by peeking one item ahead.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use tidos::{view, Page};
+///
+/// let names = vec!["Bob", "Alice", "Eve"];
+/// let mut page = Page::new();
+/// let page = &mut page;
+///
+/// view! {
+///     {#for name in names; info}
+///         <span>{name}</span>
+///         {#if !info.last}
+///             {", "}
+///         {/if}
+///     {/for}
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LoopInfo {
+	/// Zero-based position of the current item.
+	pub index: usize,
+	/// `true` for the first item.
+	pub first: bool,
+	/// `true` for the last item.
+	pub last: bool,
+}
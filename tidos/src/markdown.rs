@@ -0,0 +1,134 @@
+//! Markdown rendered to HTML, either at compile time with
+//! [`markdown!`](macro@crate::markdown) or at runtime with `@markdown{expr}`.
+//! Requires the `markdown` feature.
+//!
+//! Both support CommonMark plus tables, footnotes, strikethrough and task
+//! lists.
+//!
+//! # Compile time
+//!
+//! `markdown!("./page.md")` reads the file relative to the calling source
+//! file, like [`scoped_css!`](macro@crate::scoped_css), and converts it while
+//! compiling. Every heading gets an `id` to link to, taken from the heading
+//! text (`## Getting started` becomes `id="getting-started"`) or from an
+//! explicit `## Getting started {#start}`. The file is trusted: raw HTML in it
+//! is kept as-is.
+//!
+//! ```rust,no_run
+//! use tidos::markdown::Markdown;
+//! use tidos::{markdown, view, Component, Page};
+//!
+//! static GUIDE: Markdown = markdown!("./lib.md");
+//!
+//! pub struct Guide;
+//!
+//! impl Component for Guide {
+//!     fn to_render(&self, page: &mut Page) {
+//!         view! {
+//!             <aside>{GUIDE.toc()}</aside>
+//!             <article>{GUIDE}</article>
+//!         }
+//!     }
+//! }
+//! ```
+//!
+//! # Runtime
+//!
+//! `@markdown{expr}` renders a string of Markdown, e.g. a comment, and cleans
+//! the result with [`html::clean`](crate::html::clean), so raw HTML and
+//! `javascript:` links in the input cannot get through. Use [`render_with`] for a different
+//! [`Policy`](crate::html::Policy).
+
+use crate::{Markup, Page, ToHtml};
+
+/// A Markdown document converted by [`markdown!`](macro@crate::markdown).
+///
+/// Renders its HTML when interpolated with `{expr}`.
+#[derive(Clone, Copy, Debug)]
+pub struct Markdown {
+	html: &'static str,
+	toc: &'static str,
+	headings: &'static [Heading],
+}
+
+/// A heading in a [`Markdown`] document.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Heading {
+	/// `1` for `#`, up to `6` for `######`.
+	pub level: u8,
+	/// The `id` attribute of the heading, to link to as `#id`.
+	pub id: &'static str,
+	/// The text of the heading, without markup.
+	pub title: &'static str,
+}
+
+impl Markdown {
+	/// A document from HTML, a table of contents and headings that are trusted
+	/// to be rendered as-is, like [`Markup::trusted_static`]. This is what
+	/// [`markdown!`](macro@crate::markdown) expands to; never pass user input
+	/// here.
+	pub const fn trusted_static(
+		html: &'static str,
+		toc: &'static str,
+		headings: &'static [Heading],
+	) -> Markdown {
+		Markdown {
+			html,
+			toc,
+			headings,
+		}
+	}
+
+	/// The document as HTML.
+	pub fn html(&self) -> Markup {
+		Markup::trusted(self.html)
+	}
+
+	/// A table of contents linking to every heading, as nested lists:
+	/// `<ul><li><a href="#id">Title</a><ul>…</ul></li></ul>`.
+	pub fn toc(&self) -> Markup {
+		Markup::trusted(self.toc)
+	}
+
+	/// Every heading of the document, in order.
+	pub fn headings(&self) -> &'static [Heading] {
+		self.headings
+	}
+}
+
+impl ToHtml for Markdown {
+	#[inline]
+	fn to_html(&self, page: &mut Page) {
+		page.push_static(self.html);
+	}
+}
+
+/// Render `markdown` to HTML and clean it with
+/// [`html::clean`](crate::html::clean).
+///
+/// This is what `@markdown{expr}` calls.
+///
+/// ```rust
+/// let markup = tidos::markdown::render("*Hi* <script>alert(1)</script>");
+/// assert_eq!(markup.as_str(), "<p><em>Hi</em> </p>\n");
+/// ```
+pub fn render(markdown: impl AsRef<str>) -> Markup {
+	crate::html::clean(to_html(markdown.as_ref()))
+}
+
+/// Render `markdown` to HTML and clean it with `policy`.
+pub fn render_with(markdown: impl AsRef<str>, policy: &crate::html::Policy) -> Markup {
+	policy.clean(to_html(markdown.as_ref()))
+}
+
+fn to_html(markdown: &str) -> String {
+	use pulldown_cmark::{Options, Parser};
+
+	let options = Options::ENABLE_TABLES
+		| Options::ENABLE_FOOTNOTES
+		| Options::ENABLE_STRIKETHROUGH
+		| Options::ENABLE_TASKLISTS;
+	let mut html = String::with_capacity(markdown.len() * 3 / 2);
+	pulldown_cmark::html::push_html(&mut html, Parser::new_ext(markdown, options));
+	html
+}
@@ -0,0 +1,114 @@
+use crate::{Page, ToHtml};
+use std::borrow::Cow;
+use std::fmt;
+
+/// HTML that is trusted to be rendered as-is.
+///
+/// `{expr}` escapes every string it renders, except a `Markup`, which is
+/// written to the page unchanged. A `Markup` can only be created in these
+/// ways, so every trusted string in a code base can be found by searching for
+/// `Markup::trusted`:
+///
+/// - [`Markup::trusted`], for HTML you vouch for, e.g. from a sanitizer or a
+///   template rendered at build time, or [`Markup::trusted_static`] in a
+///   `const`.
+/// - [`Page::capture`] or [`Component::to_markup`](crate::Component::to_markup),
+///   which render templates that were already escaped by
+///   [`view!`](macro@crate::view).
+/// - `highlight!` *(feature: `highlight`)*, which escapes the code it
+///   highlights at compile time and expands to `Markup::trusted_static`.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use tidos::{view, Component, Markup, Page, Props};
+///
+/// #[derive(Props)]
+/// pub struct Article {
+///     pub body: Markup,
+/// }
+///
+/// impl Component for Article {
+///     fn to_render(&self, page: &mut Page) {
+///         view! { <article>{&self.body}</article> }
+///     }
+/// }
+///
+/// let mut page = Page::new();
+/// let page = &mut page;
+/// let body = page.capture(|page| {
+///     view! { <p>{"Hello & welcome"}</p> }
+/// });
+/// view! { <Article body={body} /> }
+/// // <article><p>Hello &amp; welcome</p></article>
+/// ```
+#[derive(Clone, Default, PartialEq, Eq, Hash)]
+pub struct Markup(Cow<'static, str>);
+
+impl Markup {
+	/// Mark `html` as trusted, so it is rendered without escaping.
+	///
+	/// Takes a `String` or a `&'static str`; the latter is never copied.
+	/// Never pass user input here without sanitizing it first.
+	#[inline]
+	pub fn trusted(html: impl Into<Cow<'static, str>>) -> Markup {
+		Markup(html.into())
+	}
+
+	/// Mark a `&'static str` of HTML as trusted in a `const` or `static`.
+	///
+	/// Like [`Markup::trusted`], never pass user input here. This is what
+	/// `highlight!` expands to.
+	#[inline]
+	pub const fn trusted_static(html: &'static str) -> Markup {
+		Markup(Cow::Borrowed(html))
+	}
+
+	/// The HTML as a string slice.
+	#[inline]
+	pub fn as_str(&self) -> &str {
+		&self.0
+	}
+
+	/// Consume the markup and return the HTML.
+	#[inline]
+	pub fn into_string(self) -> String {
+		self.0.into_owned()
+	}
+}
+
+impl fmt::Debug for Markup {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_tuple("Markup").field(&self.0).finish()
+	}
+}
+
+impl ToHtml for Markup {
+	#[inline]
+	fn to_html(&self, page: &mut Page) {
+		page.push_static(&self.0);
+	}
+}
+
+impl Page {
+	/// Render `render` and return its output as [`Markup`] instead of adding
+	/// it to the page.
+	///
+	/// Elements that `render` adds to `<head>`, such as scoped CSS, stay on
+	/// this page.
+	///
+	/// ```rust,no_run
+	/// use tidos::{view, Page};
+	///
+	/// let mut page = Page::new();
+	/// let greeting = page.capture(|page| {
+	///     view! { <strong>{"Hi"}</strong> }
+	/// });
+	/// assert_eq!(greeting.as_str(), "<strong>Hi</strong>");
+	/// ```
+	pub fn capture(&mut self, render: impl FnOnce(&mut Page)) -> Markup {
+		let start = self.template.len();
+		render(self);
+		Markup(Cow::Owned(self.template.split_off(start)))
+	}
+}
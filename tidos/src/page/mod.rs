@@ -126,6 +126,9 @@ impl Page {
 /// - `&str` / `&'static str` → [`Page::push_static`]
 /// - `String` → [`Page::push_dynamic`]
 /// - `Cow<'_, str>` → zero allocation when the cow is `Borrowed`
+///
+/// The values of `{expr}` text blocks are wrapped so they are escaped and
+/// written through [`ToHtml`](crate::ToHtml) instead.
 pub trait PushIntoPage {
 	fn push_into_page(self, page: &mut Page);
 }
@@ -158,6 +161,144 @@ impl<'a> PushIntoPage for Cow<'a, str> {
 	}
 }
 
+/// Dispatch trait used by the [`view!`](macro@crate::view) macro to render an
+/// attribute whose value is an expression, e.g. `title={ self.title }`.
+///
+/// The implementation decides whether the attribute is written at all, which
+/// makes optional and boolean attributes work without wrapping the element in
+/// an `{#if}` block:
+///
+/// - text values, i.e. the [`ToHtml`](crate::ToHtml) types such as `&str`,
+///   `String`, numbers, `char` and [`Fmt`](crate::Fmt) → `name="value"`, with
+///   the value escaped
+/// - `bool` → `name` when `true`, nothing when `false`, except for `aria-*`
+///   attributes, which get `aria-expanded="true"` or `aria-expanded="false"`.
+///   For other attributes that need the strings, such as `data-*`, pass
+///   `flag.to_string()`.
+/// - `Option<T>` → rendered as `T` when `Some`, omitted entirely when `None`
+///
+/// Any other type implementing `AsRef<str>`, such as `Rc<str>` or your own
+/// string type, is written as `name="value"` too.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use tidos::{view, Page};
+///
+/// let title: Option<&str> = None;
+/// let hidden = true;
+/// let count = 3;
+/// let mut page = Page::new();
+/// let page = &mut page;
+///
+/// view! {
+///     <p title={title} hidden={hidden} data-count={count}>{"Hello"}</p>
+/// }
+/// // <p hidden data-count="3" >Hello</p>
+/// ```
+#[diagnostic::on_unimplemented(
+	message = "`{Self}` cannot be rendered as an attribute value",
+	label = "the attribute needs a type implementing `tidos::AttributeValue` or `AsRef<str>`",
+	note = "wrap values that implement `Display` in `tidos::Fmt`, e.g. `{{tidos::Fmt(&value)}}`"
+)]
+pub trait AttributeValue {
+	/// Push ` name="value" `-style output for this value into `page`, or
+	/// nothing at all if the attribute should be omitted.
+	fn push_attribute(&self, name: &str, page: &mut Page);
+}
+
+/// Write `name="value" `, with `value` escaped as it is written into the page.
+#[inline]
+pub(crate) fn push_text_attribute(name: &str, page: &mut Page, value: impl FnOnce(&mut Page)) {
+	page.push_static(name);
+	page.push_static("=\"");
+	value(page);
+	page.push_static("\" ");
+}
+
+macro_rules! impl_attribute_value_for_text {
+	($($ty:ty)*) => {
+		$(
+			impl AttributeValue for $ty {
+				#[inline]
+				fn push_attribute(&self, name: &str, page: &mut Page) {
+					push_text_attribute(name, page, |page| crate::ToHtml::to_html(self, page));
+				}
+			}
+		)*
+	};
+}
+
+impl_attribute_value_for_text!(
+	str String Cow<'_, str> char
+	u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize f32 f64
+);
+
+impl<T: std::fmt::Display> AttributeValue for crate::Fmt<T> {
+	#[inline]
+	fn push_attribute(&self, name: &str, page: &mut Page) {
+		push_text_attribute(name, page, |page| crate::ToHtml::to_html(self, page));
+	}
+}
+
+impl AttributeValue for bool {
+	#[inline]
+	fn push_attribute(&self, name: &str, page: &mut Page) {
+		// ARIA states are enumerated, `aria-expanded` alone means nothing.
+		if name.starts_with("aria-") {
+			let value = if *self { "true" } else { "false" };
+			push_text_attribute(name, page, |page| page.push_static(value));
+		} else if *self {
+			page.push_static(name);
+			page.push_static(" ");
+		}
+	}
+}
+
+impl<T: AttributeValue> AttributeValue for Option<T> {
+	#[inline]
+	fn push_attribute(&self, name: &str, page: &mut Page) {
+		if let Some(value) = self {
+			value.push_attribute(name, page);
+		}
+	}
+}
+
+impl<T: AttributeValue + ?Sized> AttributeValue for &T {
+	#[inline]
+	fn push_attribute(&self, name: &str, page: &mut Page) {
+		(**self).push_attribute(name, page);
+	}
+}
+
+impl<T: AttributeValue + ?Sized> AttributeValue for &mut T {
+	#[inline]
+	fn push_attribute(&self, name: &str, page: &mut Page) {
+		(**self).push_attribute(name, page);
+	}
+}
+
+impl<T: AttributeValue + ?Sized> AttributeValue for Box<T> {
+	#[inline]
+	fn push_attribute(&self, name: &str, page: &mut Page) {
+		(**self).push_attribute(name, page);
+	}
+}
+
+impl<T: AttributeValue + ?Sized> AttributeValue for std::rc::Rc<T> {
+	#[inline]
+	fn push_attribute(&self, name: &str, page: &mut Page) {
+		(**self).push_attribute(name, page);
+	}
+}
+
+impl<T: AttributeValue + ?Sized> AttributeValue for std::sync::Arc<T> {
+	#[inline]
+	fn push_attribute(&self, name: &str, page: &mut Page) {
+		(**self).push_attribute(name, page);
+	}
+}
+
 /// A request guard / extractor that parses a locale from a URL path segment.
 ///
 /// Available whenever the `i18n` feature is enabled. Framework-specific
@@ -0,0 +1,16 @@
+use actix_web::http::header;
+use actix_web::{web, HttpResponse, Resource};
+
+/// A resource serving the stylesheet at [`path`](super::path).
+pub fn service() -> Resource {
+	web::resource(super::path()).route(web::get().to(handler))
+}
+
+/// Responds with the stylesheet, for mounting at [`path`](super::path) by
+/// hand.
+pub async fn handler() -> HttpResponse {
+	HttpResponse::Ok()
+		.content_type(super::CONTENT_TYPE)
+		.insert_header((header::CACHE_CONTROL, super::CACHE_CONTROL))
+		.body(super::stylesheet())
+}
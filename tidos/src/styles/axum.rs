@@ -0,0 +1,24 @@
+use axum::http::header;
+use axum::response::IntoResponse;
+use axum::routing::get;
+use axum::Router;
+
+/// A router serving the stylesheet at [`path`](super::path).
+pub fn router<S>() -> Router<S>
+where
+	S: Clone + Send + Sync + 'static,
+{
+	Router::new().route(super::path(), get(handler))
+}
+
+/// Responds with the stylesheet, for mounting at [`path`](super::path) by
+/// hand.
+pub async fn handler() -> impl IntoResponse {
+	(
+		[
+			(header::CONTENT_TYPE, super::CONTENT_TYPE),
+			(header::CACHE_CONTROL, super::CACHE_CONTROL),
+		],
+		super::stylesheet(),
+	)
+}
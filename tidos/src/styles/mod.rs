@@ -0,0 +1,87 @@
+//! All scoped CSS of the application as one cacheable stylesheet.
+//!
+//! With the `extract-css` feature, [`scoped_css!`](macro@crate::scoped_css)
+//! doesn't inline a `<style>` tag in every page. Each call registers its
+//! stylesheet here while compiling, and only adds a
+//! `<link rel="stylesheet">` to [`path`]. The path contains a hash of the
+//! combined CSS, so the file can be cached forever: a changed stylesheet gets
+//! a new URL.
+//!
+//! Serve it with the integration of your web framework:
+//!
+//! ```rust,ignore
+//! // axum
+//! let app = Router::new().merge(tidos::styles::axum::router());
+//! // Rocket
+//! rocket::build().mount("/", tidos::styles::rocket::routes());
+//! // Actix Web
+//! App::new().service(tidos::styles::actix_web::service());
+//! // warp
+//! let routes = tidos::styles::warp::filter().or(pages);
+//! ```
+//!
+//! Or serve [`stylesheet`] at [`path`] yourself, with
+//! [`CACHE_CONTROL`] as the `Cache-Control` header.
+
+use std::collections::BTreeMap;
+use std::sync::LazyLock;
+use xxhash_rust::xxh3::xxh3_64;
+
+#[cfg(feature = "actix-web")]
+pub mod actix_web;
+#[cfg(feature = "axum")]
+pub mod axum;
+#[cfg(feature = "rocket")]
+pub mod rocket;
+#[cfg(feature = "warp")]
+pub mod warp;
+
+/// The `Cache-Control` header for the stylesheet, whose URL changes with
+/// its contents.
+pub const CACHE_CONTROL: &str = "public, max-age=31536000, immutable";
+
+/// The `Content-Type` header for the stylesheet.
+pub const CONTENT_TYPE: &str = "text/css; charset=utf-8";
+
+/// A stylesheet registered by `scoped_css!`.
+#[doc(hidden)]
+pub struct ScopedStyle {
+	pub id: &'static str,
+	pub css: &'static str,
+}
+
+inventory::collect!(ScopedStyle);
+
+struct Stylesheet {
+	css: String,
+	path: String,
+	link: String,
+}
+
+static STYLESHEET: LazyLock<Stylesheet> = LazyLock::new(|| {
+	// A stylesheet used in several views is registered once per view. Sorted
+	// by id, so the output doesn't depend on the link order.
+	let styles = inventory::iter::<ScopedStyle>
+		.into_iter()
+		.map(|style| (style.id, style.css))
+		.collect::<BTreeMap<_, _>>();
+	let css = styles.into_values().collect::<String>();
+	let path = format!("/tidos/styles-{:08x}.css", xxh3_64(css.as_bytes()) as u32);
+	let link = format!("<link rel=\"stylesheet\" href=\"{path}\">");
+	Stylesheet { css, path, link }
+});
+
+/// The scoped CSS of every view in the application.
+pub fn stylesheet() -> &'static str {
+	&STYLESHEET.css
+}
+
+/// Where the stylesheet is served, e.g. `/tidos/styles-3fa1c2d0.css`.
+pub fn path() -> &'static str {
+	&STYLESHEET.path
+}
+
+/// The `<link>` that `scoped_css!` adds to the page head.
+pub fn link() -> &'static str {
+	&STYLESHEET.link
+}
@@ -0,0 +1,26 @@
+use rocket::http::{ContentType, Method};
+use rocket::route::{Handler, Outcome, Route};
+use rocket::{Data, Request, Response};
+use std::io::Cursor;
+
+/// The route serving the stylesheet at [`path`](super::path), to mount at
+/// `/`.
+pub fn routes() -> Vec<Route> {
+	vec![Route::new(Method::Get, super::path(), Stylesheet)]
+}
+
+#[derive(Clone)]
+struct Stylesheet;
+
+#[rocket::async_trait]
+impl Handler for Stylesheet {
+	async fn handle<'r>(&self, _request: &'r Request<'_>, _data: Data<'r>) -> Outcome<'r> {
+		let css = super::stylesheet();
+		let response = Response::build()
+			.header(ContentType::CSS)
+			.raw_header("Cache-Control", super::CACHE_CONTROL)
+			.sized_body(css.len(), Cursor::new(css))
+			.finalize();
+		Outcome::Success(response)
+	}
+}
@@ -0,0 +1,19 @@
+use warp::http::header;
+use warp::{Filter, Rejection, Reply};
+
+/// A filter serving the stylesheet at [`path`](super::path).
+pub fn filter() -> impl Filter<Extract = (impl Reply,), Error = Rejection> + Clone {
+	let file = super::path().trim_start_matches("/tidos/");
+	warp::get()
+		.and(warp::path("tidos"))
+		.and(warp::path(file))
+		.and(warp::path::end())
+		.map(|| {
+			let reply = warp::reply::with_header(
+				super::stylesheet(),
+				header::CONTENT_TYPE,
+				super::CONTENT_TYPE,
+			);
+			warp::reply::with_header(reply, header::CACHE_CONTROL, super::CACHE_CONTROL)
+		})
+}
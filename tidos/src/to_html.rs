@@ -0,0 +1,219 @@
+use crate::Page;
+use std::borrow::Cow;
+use std::fmt;
+use std::rc::Rc;
+use std::sync::Arc;
+
+/// Values that [`view!`](macro@crate::view) can interpolate as text, e.g.
+/// `{self.count}` or `{user.name}`.
+///
+/// Implementations write straight into the [`Page`] buffer, escaping any
+/// `& < > " '` on the way, so interpolating a value never allocates an
+/// intermediate `String`.
+///
+/// | Type | Renders |
+/// |---|---|
+/// | `str`, `String`, `Cow<'_, str>`, `char` | The text, escaped |
+/// | Integers | Their decimal digits, formatted without allocating |
+/// | `f32`, `f64` | The same text as their `Display` implementation |
+/// | `bool` | `true` or `false` |
+/// | `Option<T>` | `T` when `Some`, nothing when `None` |
+/// | [`Fmt<T>`] | Any `Display` value, escaped |
+///
+/// Any other type implementing `AsRef<str>`, such as your own string type, is
+/// rendered as its escaped text too.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use tidos::{view, Component, Page};
+///
+/// pub struct Cart {
+///     pub items: u32,
+///     pub total: f64,
+///     pub coupon: Option<String>,
+/// }
+///
+/// impl Component for Cart {
+///     fn to_render(&self, page: &mut Page) {
+///         view! {
+///             <p>{self.items}{" items, €"}{self.total}</p>
+///             <p>{self.coupon}</p>
+///         }
+///     }
+/// }
+/// ```
+#[diagnostic::on_unimplemented(
+	message = "`{Self}` cannot be rendered as HTML text",
+	label = "`{{…}}` needs a type implementing `tidos::ToHtml`",
+	note = "wrap values that implement `Display` in `tidos::Fmt`, e.g. `{{tidos::Fmt(&value)}}`"
+)]
+pub trait ToHtml {
+	/// Write `self` into `page` as escaped HTML text.
+	fn to_html(&self, page: &mut Page);
+}
+
+/// Renders any [`Display`](fmt::Display) value through [`ToHtml`], escaping
+/// the formatted output.
+///
+/// ```rust,no_run
+/// use std::net::Ipv4Addr;
+/// use tidos::{view, Fmt, Page};
+///
+/// let address = Ipv4Addr::LOCALHOST;
+/// let mut page = Page::new();
+/// let page = &mut page;
+///
+/// view! { <code>{Fmt(address)}</code> }
+/// // <code>127.0.0.1</code>
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Fmt<T>(pub T);
+
+/// Append `input` to `output`, escaping the characters that are unsafe in
+/// HTML text and attribute values.
+pub(crate) fn escape_into(output: &mut String, input: &str) {
+	let mut rest = input;
+	while let Some(index) = rest.find(['&', '<', '>', '"', '\'']) {
+		output.push_str(&rest[..index]);
+		output.push_str(match rest.as_bytes()[index] {
+			b'&' => "&amp;",
+			b'<' => "&lt;",
+			b'>' => "&gt;",
+			b'"' => "&quot;",
+			_ => "&#x27;",
+		});
+		rest = &rest[index + 1..];
+	}
+	output.push_str(rest);
+}
+
+/// Escapes everything written to it, so `Display` output can go straight into
+/// the page buffer.
+struct EscapeWriter<'a>(&'a mut String);
+
+impl fmt::Write for EscapeWriter<'_> {
+	#[inline]
+	fn write_str(&mut self, s: &str) -> fmt::Result {
+		escape_into(self.0, s);
+		Ok(())
+	}
+}
+
+impl<T: fmt::Display> ToHtml for Fmt<T> {
+	fn to_html(&self, page: &mut Page) {
+		use fmt::Write;
+		// Writing into a `String` cannot fail; an error here can only come
+		// from a `Display` implementation, which has nothing left to write.
+		let _ = write!(EscapeWriter(&mut page.template), "{}", self.0);
+	}
+}
+
+impl ToHtml for str {
+	#[inline]
+	fn to_html(&self, page: &mut Page) {
+		escape_into(&mut page.template, self);
+	}
+}
+
+impl ToHtml for String {
+	#[inline]
+	fn to_html(&self, page: &mut Page) {
+		self.as_str().to_html(page);
+	}
+}
+
+impl ToHtml for Cow<'_, str> {
+	#[inline]
+	fn to_html(&self, page: &mut Page) {
+		self.as_ref().to_html(page);
+	}
+}
+
+impl ToHtml for char {
+	#[inline]
+	fn to_html(&self, page: &mut Page) {
+		self.encode_utf8(&mut [0; 4]).to_html(page);
+	}
+}
+
+impl ToHtml for bool {
+	#[inline]
+	fn to_html(&self, page: &mut Page) {
+		page.push_static(if *self { "true" } else { "false" });
+	}
+}
+
+macro_rules! impl_to_html_for_integers {
+	($($ty:ty)*) => {
+		$(
+			impl ToHtml for $ty {
+				#[inline]
+				fn to_html(&self, page: &mut Page) {
+					page.push_static(itoa::Buffer::new().format(*self));
+				}
+			}
+		)*
+	};
+}
+
+impl_to_html_for_integers!(u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize);
+
+macro_rules! impl_to_html_for_floats {
+	($($ty:ty)*) => {
+		$(
+			impl ToHtml for $ty {
+				#[inline]
+				fn to_html(&self, page: &mut Page) {
+					Fmt(self).to_html(page);
+				}
+			}
+		)*
+	};
+}
+
+impl_to_html_for_floats!(f32 f64);
+
+impl<T: ToHtml> ToHtml for Option<T> {
+	#[inline]
+	fn to_html(&self, page: &mut Page) {
+		if let Some(value) = self {
+			value.to_html(page);
+		}
+	}
+}
+
+impl<T: ToHtml + ?Sized> ToHtml for &T {
+	#[inline]
+	fn to_html(&self, page: &mut Page) {
+		(**self).to_html(page);
+	}
+}
+
+impl<T: ToHtml + ?Sized> ToHtml for &mut T {
+	#[inline]
+	fn to_html(&self, page: &mut Page) {
+		(**self).to_html(page);
+	}
+}
+
+impl<T: ToHtml + ?Sized> ToHtml for Box<T> {
+	#[inline]
+	fn to_html(&self, page: &mut Page) {
+		(**self).to_html(page);
+	}
+}
+
+impl<T: ToHtml + ?Sized> ToHtml for Rc<T> {
+	#[inline]
+	fn to_html(&self, page: &mut Page) {
+		(**self).to_html(page);
+	}
+}
+
+impl<T: ToHtml + ?Sized> ToHtml for Arc<T> {
+	#[inline]
+	fn to_html(&self, page: &mut Page) {
+		(**self).to_html(page);
+	}
+}
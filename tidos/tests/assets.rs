@@ -0,0 +1,71 @@
+#![cfg(feature = "assets")]
+
+use tidos::{asset, assets, view, Page};
+
+#[test]
+fn fingerprints_the_url() {
+	let url = asset!("./assets/logo.svg");
+	assert!(url.starts_with("/assets/logo."));
+	assert!(url.ends_with(".svg"));
+	assert_eq!(url.len(), "/assets/logo..svg".len() + 8);
+	assert_eq!(url, asset!("./assets/logo.svg"));
+}
+
+#[test]
+fn serves_the_embedded_file() {
+	let url = asset!("./assets/site.css");
+	let asset = assets::get(url).unwrap();
+	assert_eq!(asset.bytes, include_bytes!("assets/site.css"));
+	assert_eq!(asset.content_type, "text/css; charset=utf-8");
+	assert_eq!(asset.source, "tests/assets/site.css");
+	assert!(assets::get("/assets/site.00000000.css").is_none());
+}
+
+#[test]
+fn manifest_lists_every_asset() {
+	let logo = asset!("./assets/logo.svg");
+	let site = asset!("./assets/site.css");
+	let manifest = assets::manifest();
+	assert_eq!(manifest["tests/assets/logo.svg"], logo);
+	assert_eq!(manifest["tests/assets/site.css"], site);
+	assert_eq!(
+		assets::manifest_json(),
+		format!("{{\"tests/assets/logo.svg\":\"{logo}\",\"tests/assets/site.css\":\"{site}\"}}")
+	);
+}
+
+#[cfg(not(feature = "i18n"))]
+#[test]
+fn in_a_view() {
+	let mut page_output = Page::new();
+	let page = &mut page_output;
+	view! { <img src={asset!("./assets/logo.svg")} alt="Logo" /> };
+	assert_eq!(
+		page_output.into_html(),
+		format!(
+			"<img src=\"{}\" alt=\"Logo\" />",
+			asset!("./assets/logo.svg")
+		)
+	);
+}
+
+#[cfg(feature = "axum")]
+#[tokio::test]
+async fn serves_with_immutable_caching() {
+	use axum::body::to_bytes;
+	use axum::extract::Path;
+
+	let url = asset!("./assets/logo.svg");
+	let file = url.trim_start_matches("/assets/").to_string();
+	let response = assets::axum::handler(Path(file)).await;
+	assert_eq!(response.headers()["content-type"], "image/svg+xml");
+	assert_eq!(
+		response.headers()["cache-control"],
+		"public, max-age=31536000, immutable"
+	);
+	let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+	assert_eq!(body, include_bytes!("assets/logo.svg").as_slice());
+
+	let missing = assets::axum::handler(Path("logo.00000000.svg".to_string())).await;
+	assert_eq!(missing.status(), 404);
+}
@@ -1,4 +1,4 @@
-use tidos::{view, Component, Page};
+use tidos::{view, Component, Page, Props};
 
 fn render(f: impl FnOnce(&mut Page)) -> String {
 	let mut p = Page::new();
@@ -127,6 +127,161 @@ fn attribute_formatted_string() {
 	);
 }
 
+#[test]
+fn optional_attribute_is_rendered_when_some() {
+	let title = Some("Greeting");
+	assert_eq!(
+		render(|page| {
+			view! {
+				<p title={title}>{"Hello"}</p>
+			}
+		}),
+		r#"<p title="Greeting" >Hello</p>"#
+	);
+}
+
+#[test]
+fn optional_attribute_is_omitted_when_none() {
+	let title: Option<String> = None;
+	assert_eq!(
+		render(|page| {
+			view! {
+				<p class="greeting" title={title}>{"Hello"}</p>
+			}
+		}),
+		r#"<p class="greeting" >Hello</p>"#
+	);
+}
+
+#[test]
+fn optional_attribute_value_is_sanitized() {
+	let title = Some(String::from(r#""><script>"#));
+	assert_eq!(
+		render(|page| {
+			view! {
+				<p title={title}>{"Hello"}</p>
+			}
+		}),
+		r#"<p title="&quot;&gt;&lt;script&gt;" >Hello</p>"#
+	);
+}
+
+#[test]
+fn boolean_attribute_expression() {
+	let hidden = true;
+	let disabled = false;
+	assert_eq!(
+		render(|page| {
+			view! {
+				<button hidden={hidden} disabled={disabled}>{"Send"}</button>
+			}
+		}),
+		r#"<button hidden >Send</button>"#
+	);
+}
+
+#[test]
+fn boolean_aria_attribute_renders_true_or_false() {
+	let expanded = true;
+	let pressed = false;
+	assert_eq!(
+		render(|page| {
+			view! {
+				<button aria-expanded={expanded} aria-pressed={pressed}>{"Menu"}</button>
+			}
+		}),
+		r#"<button aria-expanded="true" aria-pressed="false" >Menu</button>"#
+	);
+}
+
+#[test]
+fn boolean_data_attribute_as_string() {
+	let flag = false;
+	assert_eq!(
+		render(|page| {
+			view! { <p data-flag={flag.to_string()} data-toggle={flag}>{"Hi"}</p> }
+		}),
+		r#"<p data-flag="false" >Hi</p>"#
+	);
+}
+
+#[test]
+fn optional_boolean_attribute_expression() {
+	let checked: Option<bool> = Some(true);
+	let required: Option<bool> = None;
+	assert_eq!(
+		render(|page| {
+			view! {
+				<input checked={checked} required={required} />
+			}
+		}),
+		r#"<input checked />"#
+	);
+}
+
+#[test]
+fn borrowed_field_attribute_expression() {
+	struct Link {
+		href: String,
+		title: Option<String>,
+	}
+
+	let link = Link {
+		href: String::from("/about"),
+		title: None,
+	};
+	assert_eq!(
+		render(|page| {
+			view! {
+				<a href={link.href} title={link.title}>{"About"}</a>
+			}
+		}),
+		r#"<a href="/about" >About</a>"#
+	);
+}
+
+#[test]
+fn attributes_accept_text_values() {
+	let count = 3_u32;
+	let ratio = 0.5;
+	let initial = 'T';
+	let missing: Option<u8> = None;
+	assert_eq!(
+		render(|page| {
+			view! {
+				<meter value={count} data-ratio={ratio} data-initial={initial} data-missing={missing} data-fmt={tidos::Fmt(count + 1)} />
+			}
+		}),
+		r#"<meter value="3" data-ratio="0.5" data-initial="T" data-fmt="4" />"#
+	);
+}
+
+#[test]
+fn attributes_accept_string_types() {
+	use std::rc::Rc;
+	use std::sync::Arc;
+
+	struct Slug(String);
+
+	impl AsRef<str> for Slug {
+		fn as_ref(&self) -> &str {
+			&self.0
+		}
+	}
+
+	let rc: Rc<str> = Rc::from("a\"b");
+	let arc: Arc<str> = Arc::from("c");
+	let slug = Slug(String::from("tom&jerry"));
+	assert_eq!(
+		render(|page| {
+			view! {
+				<a title={rc} data-arc={arc} href={slug}>{"Link"}</a>
+			}
+		}),
+		r#"<a title="a&quot;b" data-arc="c" href="tom&amp;jerry" >Link</a>"#
+	);
+}
+
 #[test]
 fn custom_element_all_attribute_styles() {
 	let active = true;
@@ -152,6 +307,7 @@ fn custom_element_all_attribute_styles() {
 #[cfg(not(feature = "i18n"))]
 #[test]
 fn struct_component_all_prop_styles() {
+	#[derive(Props)]
 	struct Widget {
 		pub class: &'static str,
 		pub label: String,
@@ -193,6 +349,7 @@ fn struct_component_all_prop_styles() {
 #[cfg(not(feature = "i18n"))]
 #[test]
 fn struct_component_literal_prop() {
+	#[derive(Props)]
 	struct Widget {
 		pub class: &'static str,
 	}
@@ -216,6 +373,7 @@ fn struct_component_literal_prop() {
 #[cfg(not(feature = "i18n"))]
 #[test]
 fn struct_component_formatted_prop() {
+	#[derive(Props)]
 	struct Widget {
 		pub label: String,
 	}
@@ -249,7 +407,7 @@ fn struct_component_default() {
 	impl Component for Coordinate {
 		fn to_render(&self, page: &mut Page) {
 			view! {
-				<span>{self.x.to_string()}</span><span>{self.y.to_string()}</span>
+				<span>{self.x}</span><span>{self.y}</span>
 			}
 		}
 	}
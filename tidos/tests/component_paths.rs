@@ -0,0 +1,187 @@
+#![cfg(not(feature = "i18n"))]
+
+use std::fmt::Display;
+use tidos::{view, Component, Page, Props};
+
+mod ui {
+	use tidos::{view, Component, Page, Props, Slot};
+
+	#[derive(Props)]
+	pub struct Card<'a> {
+		pub title: &'static str,
+		pub _0: Slot<'a>,
+	}
+
+	impl Component for Card<'_> {
+		fn to_render(&self, page: &mut Page) {
+			view! {
+				<section>
+					<h2>{self.title}</h2>
+					@slot{self._0}
+				</section>
+			}
+		}
+	}
+
+	pub mod icons {
+		use tidos::{view, Component, Page, Props};
+
+		#[derive(Props, Default)]
+		pub struct Star {
+			pub size: u8,
+		}
+
+		impl Component for Star {
+			fn to_render(&self, page: &mut Page) {
+				view! { <i class="star" data-size={self.size}></i> }
+			}
+		}
+	}
+}
+
+#[derive(Props)]
+struct List<'a, T> {
+	items: &'a [T],
+}
+
+impl<T: Display> Component for List<'_, T> {
+	fn to_render(&self, page: &mut Page) {
+		view! {
+			<ul>
+				{#for item in self.items}
+					<li>{item.to_string()}</li>
+				{/for}
+			</ul>
+		}
+	}
+}
+
+trait Layout {
+	type Icon: Component;
+
+	fn render_layout(&self, page: &mut Page);
+}
+
+struct Shell;
+
+impl Layout for Shell {
+	type Icon = ui::icons::Star;
+
+	fn render_layout(&self, page: &mut Page) {
+		view! {
+			<nav>
+				<Self::Icon size={2}></Self::Icon>
+				<Self::Icon ../>
+			</nav>
+		}
+	}
+}
+
+fn render(f: impl FnOnce(&mut Page)) -> String {
+	let mut page = Page::new();
+	f(&mut page);
+	page.into_html()
+}
+
+#[test]
+fn module_path_component() {
+	let html = render(|page| {
+		view! { <ui::Card title="News"><p>{"Today"}</p></ui::Card> }
+	});
+	assert_eq!(html, "<section><h2>News</h2><p>Today</p></section>");
+}
+
+#[test]
+fn nested_module_path_component() {
+	let html = render(|page| {
+		view! { <ui::icons::Star size={3} /> }
+	});
+	assert_eq!(html, r#"<i class="star" data-size="3" ></i>"#);
+}
+
+#[test]
+fn crate_path_component() {
+	let html = render(|page| {
+		view! { <crate::ui::icons::Star size={1} /> }
+	});
+	assert_eq!(html, r#"<i class="star" data-size="1" ></i>"#);
+}
+
+#[test]
+fn generic_component() {
+	let numbers = vec![1, 2];
+	let html = render(|page| {
+		view! { <List<'_, u32> items={&numbers} /> }
+	});
+	assert_eq!(html, "<ul><li>1</li><li>2</li></ul>");
+}
+
+#[test]
+fn generic_component_with_turbofish() {
+	let names = ["Ada", "Grace"];
+	let html = render(|page| {
+		view! { <List::<&str> items={&names}></List::<&str>> }
+	});
+	assert_eq!(html, "<ul><li>Ada</li><li>Grace</li></ul>");
+}
+
+#[test]
+fn closing_tag_may_spell_generics_differently() {
+	let names = ["Ada"];
+	let html = render(|page| {
+		view! { <List<&str> items={&names}></List::<&str>> }
+	});
+	assert_eq!(html, "<ul><li>Ada</li></ul>");
+}
+
+#[test]
+fn self_qualified_component() {
+	let html = render(|page| Shell.render_layout(page));
+	assert_eq!(
+		html,
+		r#"<nav><i class="star" data-size="2" ></i><i class="star" data-size="0" ></i></nav>"#
+	);
+}
+
+enum Status {
+	Online,
+}
+
+struct StatusBuilder;
+
+impl StatusBuilder {
+	fn build(self) -> Status {
+		Status::Online
+	}
+}
+
+impl Props for Status {
+	type Builder = StatusBuilder;
+
+	fn builder() -> StatusBuilder {
+		StatusBuilder
+	}
+}
+
+impl Component for Status {
+	fn to_render(&self, page: &mut Page) {
+		let label = match self {
+			Status::Online => "online",
+		};
+		view! { <span>{label}</span> }
+	}
+}
+
+#[test]
+fn enum_component_with_closing_tag() {
+	let html = render(|page| {
+		view! { <Status></Status> }
+	});
+	assert_eq!(html, "<span>online</span>");
+}
+
+#[test]
+fn invalid_component_paths_are_rejected_at_compile_time() {
+	let t = trybuild::TestCases::new();
+	t.compile_fail("tests/component_paths/panics/*.rs");
+}
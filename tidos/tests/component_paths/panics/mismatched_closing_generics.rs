@@ -0,0 +1,25 @@
+use std::fmt::Display;
+use tidos::{view, Component, Page, Props};
+
+#[derive(Props)]
+struct List<T> {
+	items: Vec<T>,
+}
+
+impl<T: Display> Component for List<T> {
+	fn to_render(&self, page: &mut Page) {
+		view! {
+			{#for item in &self.items}
+				<li>{item.to_string()}</li>
+			{/for}
+		}
+	}
+}
+
+fn main() {
+	let mut page = Page::new();
+	let page = &mut page;
+	view! {
+		<List<u8> items={vec![1]}></List<u16>>
+	}
+}
@@ -0,0 +1,24 @@
+use tidos::{view, Page};
+
+mod ui {
+	use tidos::{view, Component, Page, Props};
+
+	#[derive(Props)]
+	pub struct Card {
+		pub title: &'static str,
+	}
+
+	impl Component for Card {
+		fn to_render(&self, page: &mut Page) {
+			view! { <h2>{self.title}</h2> }
+		}
+	}
+}
+
+fn main() {
+	let mut page = Page::new();
+	let page = &mut page;
+	view! {
+		<ui::Card title="News"></Card>
+	}
+}
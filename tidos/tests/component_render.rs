@@ -0,0 +1,158 @@
+#![cfg(not(feature = "i18n"))]
+
+use tidos::{view, Component, Page};
+
+struct Badge(&'static str);
+
+impl Component for Badge {
+	fn to_render(&self, page: &mut Page) {
+		view! { <b>{self.0}</b> }
+	}
+}
+
+struct Divider;
+
+impl Component for Divider {
+	fn to_render(&self, page: &mut Page) {
+		view! { <hr /> }
+	}
+}
+
+struct Sidebar {
+	banner: Option<Box<dyn Component>>,
+	widgets: Vec<Box<dyn Component>>,
+}
+
+impl Sidebar {
+	fn first_widget(&self) -> Option<&dyn Component> {
+		self.widgets.first().map(|widget| &**widget)
+	}
+}
+
+impl Component for Sidebar {
+	fn to_render(&self, page: &mut Page) {
+		view! {
+			<aside>
+				@render{self.banner}
+				@render{self.widgets}
+			</aside>
+		}
+	}
+}
+
+fn render(f: impl FnOnce(&mut Page)) -> String {
+	let mut page = Page::new();
+	f(&mut page);
+	page.into_html()
+}
+
+#[test]
+fn render_component_value() {
+	let badge = Badge("new");
+	let html = render(|page| {
+		view! { <p>@render{badge}</p> }
+	});
+	assert_eq!(html, "<p><b>new</b></p>");
+}
+
+#[test]
+fn render_reference() {
+	let badge = &Badge("ref");
+	let html = render(|page| {
+		view! { @render{badge} }
+	});
+	assert_eq!(html, "<b>ref</b>");
+}
+
+#[test]
+fn render_trait_objects() {
+	let sidebar = Sidebar {
+		banner: Some(Box::new(Badge("sale"))),
+		widgets: vec![Box::new(Divider), Box::new(Badge("hot"))],
+	};
+	let html = render(|page| sidebar.to_render(page));
+	assert_eq!(html, "<aside><b>sale</b><hr/><b>hot</b></aside>");
+}
+
+#[test]
+fn render_none_renders_nothing() {
+	let sidebar = Sidebar {
+		banner: None,
+		widgets: vec![],
+	};
+	let html = render(|page| sidebar.to_render(page));
+	assert_eq!(html, "<aside></aside>");
+}
+
+#[test]
+fn render_method_call() {
+	let sidebar = Sidebar {
+		banner: None,
+		widgets: vec![Box::new(Badge("first")), Box::new(Divider)],
+	};
+	let html = render(|page| {
+		view! { @render{sidebar.first_widget()} }
+	});
+	assert_eq!(html, "<b>first</b>");
+}
+
+#[test]
+fn render_slice() {
+	let badges = [Badge("a"), Badge("b"), Badge("c")];
+	let html = render(|page| {
+		view! { @render{badges[1..]} }
+	});
+	assert_eq!(html, "<b>b</b><b>c</b>");
+}
+
+#[test]
+fn render_tuple() {
+	let html = render(|page| {
+		view! { @render{(Badge("one"), Divider, Some(Badge("two")))} }
+	});
+	assert_eq!(html, "<b>one</b><hr/><b>two</b>");
+}
+
+#[test]
+fn render_alongside_snippet() {
+	let badges = vec![Badge("x")];
+	let html = render(|page| {
+		view! {
+			{#snippet label(text: &str)}
+				<i>{text}</i>
+			{/snippet}
+			@render{label("tags")}
+			@render{badges}
+		}
+	});
+	assert_eq!(html, "<i>tags</i><b>x</b>");
+}
+
+fn badge(label: &'static str) -> Badge {
+	Badge(label)
+}
+
+#[test]
+fn render_function_call() {
+	let html = render(|page| {
+		view! { @render{badge("fn")} }
+	});
+	assert_eq!(html, "<b>fn</b>");
+}
+
+#[test]
+fn snippet_is_only_called_where_it_is_declared() {
+	let html = render(|page| {
+		view! {
+			@render{badge("before")}
+			{#if true}
+				{#snippet badge(text: &str)}
+					<i>{text}</i>
+				{/snippet}
+				@render{badge("snippet")}
+			{/if}
+			@render{badge("after")}
+		}
+	});
+	assert_eq!(html, "<b>before</b><i>snippet</i><b>after</b>");
+}
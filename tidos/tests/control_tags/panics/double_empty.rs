@@ -0,0 +1,16 @@
+use tidos::view;
+
+fn double_empty() {
+	let names: Vec<&str> = vec![];
+	view! {
+		{#for name in names}
+			<p>{name}</p>
+		{:empty}
+			<p>{"No names"}</p>
+		{:empty}
+			<p>{"Still no names?"}</p>
+		{/for}
+	};
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+use tidos::view;
+
+fn keyword_loop_binding() {
+	let names = vec!["Bob", "Alice"];
+	view! {
+		{#for name in names; loop}
+			<p>{name}</p>
+		{/for}
+	};
+}
+
+fn main() {}
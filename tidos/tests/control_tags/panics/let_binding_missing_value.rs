@@ -0,0 +1,10 @@
+use tidos::view;
+
+fn let_binding_missing_value() {
+	view! {
+		{@let total}
+		<p>{total}</p>
+	};
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use tidos::view;
+
+fn misspelled_statement_tag() {
+	view! {
+		{@const total = 42}
+		<p>{total.to_string()}</p>
+	};
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+use tidos::{view, Page};
+
+fn render_without_call(page: &mut Page) {
+	view! {
+		{#snippet divider()}
+			<hr />
+		{/snippet}
+		@render{divider}
+	};
+}
+
+fn main() {}
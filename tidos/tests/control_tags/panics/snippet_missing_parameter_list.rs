@@ -0,0 +1,11 @@
+use tidos::view;
+
+fn snippet_missing_parameter_list() {
+	view! {
+		{#snippet divider}
+			<hr />
+		{/snippet}
+	};
+}
+
+fn main() {}
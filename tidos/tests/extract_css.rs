@@ -0,0 +1,58 @@
+#![cfg(feature = "extract-css")]
+
+use tidos::{scoped_css, styles, view, Page};
+
+#[cfg(not(feature = "i18n"))]
+#[test]
+fn links_the_stylesheet_instead_of_inlining_it() {
+	let mut page_output = Page::new();
+	let page = &mut page_output;
+	view! { <div class={scoped_css!("./scoped_css/card.css")}></div> };
+	view! { <p class={scoped_css!("./scoped_css/test.css")}>{"Body"}</p> };
+
+	assert!(!page_output.head.contains("<style>"));
+	assert_eq!(
+		page_output.head,
+		format!("<link rel=\"stylesheet\" href=\"{}\">", styles::path())
+	);
+}
+
+#[cfg(not(feature = "i18n"))]
+#[test]
+fn collects_every_stylesheet_once() {
+	let mut page_output = Page::new();
+	let page = &mut page_output;
+	view! { <div class={scoped_css!("./scoped_css/card.css")}></div> };
+	let card = page_output.template.split('"').nth(1).unwrap();
+	let css = styles::stylesheet();
+	assert!(css.contains(&format!(".{card}{{border-radius:8px}}")));
+	assert!(css.contains("color:red"));
+	assert_eq!(css.matches("border-radius:8px").count(), 1);
+}
+
+#[test]
+fn path_is_hashed_from_the_stylesheet() {
+	let path = styles::path();
+	assert!(path.starts_with("/tidos/styles-"));
+	assert!(path.ends_with(".css"));
+	assert_eq!(path.len(), "/tidos/styles-.css".len() + 8);
+}
+
+#[cfg(feature = "axum")]
+#[tokio::test]
+async fn serves_with_immutable_caching() {
+	use axum::body::to_bytes;
+	use axum::response::IntoResponse;
+
+	let response = styles::axum::handler().await.into_response();
+	assert_eq!(
+		response.headers()["content-type"],
+		"text/css; charset=utf-8"
+	);
+	assert_eq!(
+		response.headers()["cache-control"],
+		"public, max-age=31536000, immutable"
+	);
+	let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+	assert_eq!(body, styles::stylesheet());
+}
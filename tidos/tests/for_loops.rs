@@ -71,3 +71,130 @@ fn a_complex_for_loop() {
 		"<main><span>0. k</span><span>1. a</span><span>2. a</span><span>3. s</span><span>4. b</span><span>5. r</span><span>6. o</span><span>7. o</span><span>8. d</span><span>9. j</span><span>10. u</span></main>"
 	);
 }
+
+#[test]
+fn empty_branch_is_rendered_for_empty_collection() {
+	let names: Vec<&str> = vec![];
+
+	assert_eq!(
+		render(|page| {
+			view! {
+				<ul>
+					{#for name in names}
+						<li>{name}</li>
+					{:empty}
+						<li>{"No names yet."}</li>
+					{/for}
+				</ul>
+			}
+		}),
+		"<ul><li>No names yet.</li></ul>"
+	);
+}
+
+#[test]
+fn empty_branch_is_skipped_for_non_empty_collection() {
+	let names = vec!["Bob", "Alice"];
+
+	assert_eq!(
+		render(|page| {
+			view! {
+				{#for name in names}
+					<p>{name}</p>
+				{:empty}
+					<p>{"No names yet."}</p>
+				{/for}
+			}
+		}),
+		"<p>Bob</p><p>Alice</p>"
+	);
+}
+
+#[test]
+fn empty_branch_with_one_shot_iterator() {
+	let words = "";
+
+	assert_eq!(
+		render(|page| {
+			view! {
+				{#for word in words.split_whitespace()}
+					<p>{word}</p>
+				{:empty}
+					<p>{"Nothing to say."}</p>
+				{/for}
+			}
+		}),
+		"<p>Nothing to say.</p>"
+	);
+}
+
+#[test]
+fn loop_info_binding() {
+	let names = vec!["Bob", "Alice", "Eve"];
+
+	assert_eq!(
+		render(|page| {
+			view! {
+				{#for name in names; info}
+					<span data-index={"{}", info.index} :first={info.first} :last={info.last}>{name}</span>
+				{/for}
+			}
+		}),
+		r#"<span data-index="0" first >Bob</span><span data-index="1" >Alice</span><span data-index="2" last >Eve</span>"#
+	);
+}
+
+#[test]
+fn loop_info_binding_with_single_item() {
+	let names = vec!["Bob"];
+
+	assert_eq!(
+		render(|page| {
+			view! {
+				{#for name in names; info}
+					{#if info.first && info.last}
+						<p>{name}</p>
+					{/if}
+				{/for}
+			}
+		}),
+		"<p>Bob</p>"
+	);
+}
+
+#[test]
+fn loop_info_binding_with_pattern_and_empty_branch() {
+	let name = String::from("abc");
+	let empty: Vec<(usize, char)> = vec![];
+
+	assert_eq!(
+		render(|page| {
+			view! {
+				{#for (i, c) in name.chars().enumerate(); info}
+					{"{}{}", i, c}
+					{#if !info.last}{", "}{/if}
+				{:empty}
+					{"empty"}
+				{/for}
+				{#for (i, c) in empty; info}
+					{"{}{}{}", i, c, info.index}
+				{:empty}
+					{" (empty)"}
+				{/for}
+			}
+		}),
+		"0a, 1b, 2c (empty)"
+	);
+}
+
+#[test]
+fn double_empty_branch() {
+	let t = trybuild::TestCases::new();
+	t.compile_fail("tests/control_tags/panics/double_empty.rs");
+}
+
+#[test]
+fn keyword_loop_binding() {
+	let t = trybuild::TestCases::new();
+	t.compile_fail("tests/control_tags/panics/keyword_loop_binding.rs");
+}
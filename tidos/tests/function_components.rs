@@ -0,0 +1,127 @@
+#![cfg(not(feature = "i18n"))]
+
+use tidos::{component, view, Component, Page, Slot};
+
+#[derive(Default)]
+pub enum Tone {
+	#[default]
+	Neutral,
+	Warning,
+}
+
+#[component]
+pub fn Badge(label: &str, #[default] tone: Tone) {
+	let class = match tone {
+		Tone::Neutral => "badge",
+		Tone::Warning => "badge badge-warning",
+	};
+	view! {
+		<span class={class}>{label}</span>
+	}
+}
+
+#[component]
+fn Greeting() {
+	view! {
+		<p>{"Hello"}</p>
+	}
+}
+
+#[component]
+fn Counter(count: usize) {
+	view! {
+		<p>{"Count: {}", count}</p>
+	}
+}
+
+#[component]
+fn Labelled<T: std::fmt::Display>(label: &str, value: T) {
+	view! {
+		<dl><dt>{label}</dt><dd>{value.to_string()}</dd></dl>
+	}
+}
+
+#[component]
+fn Frame(title: String, body: Slot<'_>) {
+	view! {
+		<section>
+			<h2>{title}</h2>
+			@slot{body}
+		</section>
+	}
+}
+
+fn render(f: impl FnOnce(&mut Page)) -> String {
+	let mut page = Page::new();
+	f(&mut page);
+	page.into_html()
+}
+
+#[test]
+fn function_component_renders_props() {
+	let html = render(|page| {
+		view! { <Badge label="New" tone={Tone::Warning} /> }
+	});
+	assert_eq!(html, r#"<span class="badge badge-warning" >New</span>"#);
+}
+
+#[test]
+fn defaulted_parameter_can_be_omitted() {
+	let html = render(|page| {
+		view! { <Badge label="Draft" /> }
+	});
+	assert_eq!(html, r#"<span class="badge" >Draft</span>"#);
+}
+
+#[test]
+fn component_without_parameters() {
+	let html = render(|page| {
+		view! { <Greeting /> }
+	});
+	assert_eq!(html, "<p>Hello</p>");
+}
+
+#[test]
+fn copy_parameter_is_available_in_body() {
+	let html = render(|page| {
+		view! { <Counter count={3} /> }
+	});
+	assert_eq!(html, "<p>Count: 3</p>");
+}
+
+#[test]
+fn generic_parameters_are_carried_over() {
+	let html = render(|page| {
+		view! { <Labelled label="Age" value={42} /> }
+	});
+	assert_eq!(html, "<dl><dt>Age</dt><dd>42</dd></dl>");
+}
+
+#[test]
+fn slot_parameter_is_filled_from_view() {
+	let html = render(|page| {
+		view! {
+			<Frame title={String::from("News")}>
+				{#slot:body}<p>{"Nothing new"}</p>{/slot}
+			</Frame>
+		}
+	});
+	assert_eq!(html, "<section><h2>News</h2><p>Nothing new</p></section>");
+}
+
+#[test]
+fn props_struct_can_be_built_directly() {
+	let badge = Badge {
+		label: "Direct",
+		tone: Tone::Neutral,
+	};
+	let mut page = Page::new();
+	badge.to_render(&mut page);
+	assert_eq!(page.into_html(), r#"<span class="badge" >Direct</span>"#);
+}
+
+#[test]
+fn invalid_function_components_are_rejected_at_compile_time() {
+	let t = trybuild::TestCases::new();
+	t.compile_fail("tests/function_components/panics/*.rs");
+}
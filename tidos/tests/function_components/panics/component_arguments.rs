@@ -0,0 +1,6 @@
+use tidos::component;
+
+#[component(Badge)]
+fn Badge(label: &str) {}
+
+fn main() {}
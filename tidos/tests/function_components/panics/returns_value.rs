@@ -0,0 +1,10 @@
+use tidos::{component, view};
+
+#[component]
+fn Badge(label: &str) -> String {
+	view! {
+		<span>{label}</span>
+	}
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use tidos::{component, view};
+
+#[component]
+fn badge(label: &str) {
+	view! {
+		<span>{label}</span>
+	}
+}
+
+fn main() {}
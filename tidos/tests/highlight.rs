@@ -0,0 +1,108 @@
+#![cfg(not(feature = "i18n"))]
+
+use tidos::highlight::STYLESHEET;
+use tidos::{highlight, view, Markup, Page};
+
+static SNIPPET: Markup = highlight!("rust", file = "./highlight/snippet.rs");
+
+#[test]
+fn rust_file_is_highlighted() {
+	let html = SNIPPET.as_str();
+	assert!(html.starts_with(
+		"<span class=\"hl-comment\">// A greeting</span>\n<span class=\"hl-keyword\">fn</span> <span class=\"hl-function\">greet</span>"
+	));
+	assert!(html.contains("<span class=\"hl-macro\">format!</span>"));
+	assert!(html.contains("<span class=\"hl-escape\">\\n</span>"));
+}
+
+#[test]
+fn inline_toml_is_highlighted() {
+	let html = highlight!(
+		"toml",
+		"[dependencies]\n# Latest release\ntidos = { version = \"0.7.2\", default-features = false }"
+	);
+	assert_eq!(
+		html.as_str(),
+		"[<span class=\"hl-type\">dependencies</span>]\n\
+		<span class=\"hl-comment\"># Latest release</span>\n\
+		<span class=\"hl-attribute\">tidos</span> <span class=\"hl-operator\">=</span> { \
+		<span class=\"hl-attribute\">version</span> <span class=\"hl-operator\">=</span> <span class=\"hl-string\">&quot;0.7.2&quot;</span>, \
+		<span class=\"hl-attribute\">default-features</span> <span class=\"hl-operator\">=</span> <span class=\"hl-constant\">false</span> }"
+	);
+}
+
+#[test]
+fn shell_is_highlighted() {
+	let html = highlight!("sh", "cargo add tidos --features highlight");
+	assert!(html
+		.as_str()
+		.starts_with("<span class=\"hl-function\">cargo</span> add tidos"));
+}
+
+#[test]
+fn code_starting_with_dot_slash_is_not_a_path() {
+	let html = highlight!("sh", "./configure --prefix=/usr");
+	assert!(html.as_str().contains("configure"));
+}
+
+#[test]
+fn javascript_is_highlighted() {
+	let html = highlight!("js", "const answer = 42; // the answer");
+	assert_eq!(
+		html.as_str(),
+		"<span class=\"hl-keyword\">const</span> <span class=\"hl-variable\">answer</span> \
+		<span class=\"hl-operator\">=</span> <span class=\"hl-number\">42</span>; \
+		<span class=\"hl-comment\">// the answer</span>"
+	);
+}
+
+#[test]
+fn html_is_escaped() {
+	let html = highlight!("html", "<a href=\"/\">Tom & Jerry</a>");
+	assert_eq!(
+		html.as_str(),
+		"&lt;<span class=\"hl-tag\">a</span> <span class=\"hl-attribute\">href</span>=\
+		<span class=\"hl-string\">&quot;/&quot;</span>&gt;Tom &amp; Jerry&lt;/<span class=\"hl-tag\">a</span>&gt;"
+	);
+}
+
+#[test]
+fn spans_do_not_cross_lines() {
+	let html = highlight!(
+		"rust",
+		"/* a\n   block\n   comment */\nlet s = \"multi\nline\";"
+	);
+	for line in html.as_str().lines() {
+		assert_eq!(
+			line.matches("<span").count(),
+			line.matches("</span>").count()
+		);
+	}
+	assert_eq!(html.as_str().lines().count(), 5);
+}
+
+#[test]
+fn highlighted_code_renders_unescaped() {
+	let mut page_output = Page::new();
+	let page = &mut page_output;
+
+	view! { <code>{highlight!("rust", "a < b")}</code> }
+
+	assert_eq!(
+		page_output.into_html(),
+		"<code>a <span class=\"hl-operator\">&lt;</span> b</code>"
+	);
+}
+
+#[test]
+fn stylesheet_covers_classes() {
+	for class in ["hl-comment", "hl-keyword", "hl-string", "hl-type", "hl-tag"] {
+		assert!(STYLESHEET.contains(&format!(".{class}")));
+	}
+}
+
+#[test]
+fn invalid_highlight_macros_are_rejected_at_compile_time() {
+	let t = trybuild::TestCases::new();
+	t.compile_fail("tests/highlight/panics/*.rs");
+}
@@ -0,0 +1,5 @@
+use tidos::{highlight, Markup};
+
+const CODE: Markup = highlight!("rust", file = "./does_not_exist.rs");
+
+fn main() {}
@@ -0,0 +1,5 @@
+use tidos::{highlight, Markup};
+
+const CODE: Markup = highlight!("klingon", "Qapla'");
+
+fn main() {}
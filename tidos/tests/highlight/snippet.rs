@@ -0,0 +1,4 @@
+// A greeting
+fn greet(name: &str) -> String {
+	format!("Hello, {name}!\n")
+}
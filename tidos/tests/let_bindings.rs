@@ -0,0 +1,101 @@
+use tidos::{view, Page};
+
+fn render(f: impl FnOnce(&mut Page)) -> String {
+	let mut p = Page::new();
+	f(&mut p);
+	p.into_html()
+}
+
+#[test]
+fn let_binding_inside_for_loop() {
+	let prices = vec![1250_u32, 99];
+
+	assert_eq!(
+		render(|page| {
+			view! {
+				{#for cents in prices}
+					{@let price = format!("€{}.{:02}", cents / 100, cents % 100)}
+					<span title={price}>{price}</span>
+				{/for}
+			}
+		}),
+		r#"<span title="€12.50" >€12.50</span><span title="€0.99" >€0.99</span>"#
+	);
+}
+
+#[test]
+fn let_binding_with_type_annotation() {
+	assert_eq!(
+		render(|page| {
+			view! {
+				{@let url: String = ["/users", "42"].join("/")}
+				<a href={url}>{"Profile"}</a>
+			}
+		}),
+		r#"<a href="/users/42" >Profile</a>"#
+	);
+}
+
+#[test]
+fn let_binding_with_pattern() {
+	let pairs = vec![("a", 1), ("b", 2)];
+
+	assert_eq!(
+		render(|page| {
+			view! {
+				{#for pair in pairs}
+					{@let (name, count) = pair}
+					<p>{"{}={}", name, count}</p>
+				{/for}
+			}
+		}),
+		"<p>a=1</p><p>b=2</p>"
+	);
+}
+
+#[test]
+fn let_binding_with_comparison_in_value() {
+	let n = 3;
+
+	assert_eq!(
+		render(|page| {
+			view! {
+				{@let is_small = n <= 5 && n != 0}
+				{#if is_small}
+					<p>{"small"}</p>
+				{/if}
+			}
+		}),
+		"<p>small</p>"
+	);
+}
+
+#[test]
+fn let_binding_is_scoped_to_block() {
+	let name = "outer";
+
+	assert_eq!(
+		render(|page| {
+			view! {
+				{#if true}
+					{@let name = "inner"}
+					<p>{name}</p>
+				{/if}
+				<p>{name}</p>
+			}
+		}),
+		"<p>inner</p><p>outer</p>"
+	);
+}
+
+#[test]
+fn let_binding_missing_value() {
+	let t = trybuild::TestCases::new();
+	t.compile_fail("tests/control_tags/panics/let_binding_missing_value.rs");
+}
+
+#[test]
+fn misspelled_statement_tag() {
+	let t = trybuild::TestCases::new();
+	t.compile_fail("tests/control_tags/panics/misspelled_statement_tag.rs");
+}
@@ -0,0 +1,119 @@
+#![cfg(not(feature = "i18n"))]
+
+use tidos::markdown::{Heading, Markdown};
+use tidos::{markdown, view, Page};
+
+static GUIDE: Markdown = markdown!("./markdown/guide.md");
+
+fn render(f: impl FnOnce(&mut Page)) -> String {
+	let mut page = Page::new();
+	f(&mut page);
+	page.into_html()
+}
+
+#[test]
+fn headings_get_ids() {
+	let html = GUIDE.html();
+	assert!(html.as_str().starts_with(
+		"<h1 id=\"guide\">Guide</h1>\n<p>Welcome to <strong>Tidos</strong>.</p>\n<h2 id=\"getting-started\">Getting started</h2>"
+	));
+	assert!(html
+		.as_str()
+		.contains("<h3 id=\"with-axum\">With <code>axum</code></h3>"));
+}
+
+#[test]
+fn duplicate_headings_get_unique_ids() {
+	assert!(GUIDE
+		.html()
+		.as_str()
+		.contains("<h2 id=\"getting-started-1\">Getting started</h2>"));
+}
+
+#[test]
+fn explicit_heading_ids_are_kept() {
+	assert!(GUIDE
+		.html()
+		.as_str()
+		.contains("<h2 id=\"tables\">Tables</h2>"));
+}
+
+#[test]
+fn tables_and_raw_html_are_rendered() {
+	let html = GUIDE.html();
+	assert!(html.as_str().contains("<table>"));
+	assert!(html
+		.as_str()
+		.contains("<div class=\"note\">Raw HTML is kept.</div>"));
+}
+
+#[test]
+fn headings_are_listed() {
+	assert_eq!(
+		GUIDE.headings(),
+		&[
+			Heading {
+				level: 1,
+				id: "guide",
+				title: "Guide"
+			},
+			Heading {
+				level: 2,
+				id: "getting-started",
+				title: "Getting started"
+			},
+			Heading {
+				level: 3,
+				id: "with-axum",
+				title: "With axum"
+			},
+			Heading {
+				level: 2,
+				id: "getting-started-1",
+				title: "Getting started"
+			},
+			Heading {
+				level: 2,
+				id: "tables",
+				title: "Tables"
+			},
+		]
+	);
+}
+
+#[test]
+fn table_of_contents_nests_headings() {
+	assert_eq!(
+		GUIDE.toc().as_str(),
+		concat!(
+			"<ul><li><a href=\"#guide\">Guide</a>",
+			"<ul><li><a href=\"#getting-started\">Getting started</a>",
+			"<ul><li><a href=\"#with-axum\">With axum</a></li></ul></li>",
+			"<li><a href=\"#getting-started-1\">Getting started</a></li>",
+			"<li><a href=\"#tables\">Tables</a></li></ul></li></ul>",
+		)
+	);
+}
+
+#[test]
+fn markdown_renders_in_view() {
+	let html = render(|page| {
+		view! { <nav>{GUIDE.toc()}</nav><article>{GUIDE}</article> }
+	});
+	assert!(html.starts_with("<nav><ul><li><a href=\"#guide\">"));
+	assert!(html.contains("</nav><article><h1 id=\"guide\">Guide</h1>"));
+}
+
+#[test]
+fn markdown_macro_in_expression_position() {
+	let html = render(|page| {
+		view! { <article>{markdown!("./markdown/guide.md")}</article> }
+	});
+	assert!(html.starts_with("<article><h1 id=\"guide\">"));
+}
+
+#[test]
+fn invalid_markdown_macros_are_rejected_at_compile_time() {
+	let t = trybuild::TestCases::new();
+	t.compile_fail("tests/markdown/panics/*.rs");
+}
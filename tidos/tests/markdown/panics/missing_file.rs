@@ -0,0 +1,6 @@
+use tidos::markdown;
+use tidos::markdown::Markdown;
+
+static PAGE: Markdown = markdown!("./does_not_exist.md");
+
+fn main() {}
@@ -0,0 +1,49 @@
+#![cfg(not(feature = "i18n"))]
+
+use tidos::html::Policy;
+use tidos::markdown::{render, render_with};
+use tidos::{view, Page};
+
+fn page_html(f: impl FnOnce(&mut Page)) -> String {
+	let mut page = Page::new();
+	f(&mut page);
+	page.into_html()
+}
+
+#[test]
+fn markdown_is_rendered() {
+	let markup = render("# Title\n\nSome *emphasis* and a [link](https://example.com).");
+	assert_eq!(
+		markup.as_str(),
+		"<h1>Title</h1>\n<p>Some <em>emphasis</em> and a <a href=\"https://example.com\" rel=\"noopener noreferrer nofollow\">link</a>.</p>\n"
+	);
+}
+
+#[test]
+fn raw_html_and_javascript_links_are_removed() {
+	let markup = render(
+		"<script>alert(1)</script><img src=x onerror=alert(1)>\n\n[click](javascript:alert(1))",
+	);
+	assert_eq!(
+		markup.as_str(),
+		"<img src=\"x\">\n<p><a rel=\"noopener noreferrer nofollow\">click</a></p>\n"
+	);
+}
+
+#[test]
+fn custom_policy() {
+	let markup = render_with("Some **bold** text", &Policy::text_only());
+	assert_eq!(markup.as_str(), "Some bold text\n");
+}
+
+#[test]
+fn markdown_in_view() {
+	let body = String::from("Hello **world**<script></script>");
+	let html = page_html(|page| {
+		view! { <article>@markdown{body}</article> }
+	});
+	assert_eq!(
+		html,
+		"<article><p>Hello <strong>world</strong></p>\n</article>"
+	);
+}
@@ -0,0 +1,95 @@
+#![cfg(not(feature = "i18n"))]
+
+use tidos::{scoped_css, view, Component, Markup, Page, Props};
+
+#[derive(Props)]
+struct Article {
+	title: &'static str,
+	body: Markup,
+	footer: Option<Markup>,
+}
+
+impl Component for Article {
+	fn to_render(&self, page: &mut Page) {
+		view! {
+			<article>
+				<h1>{self.title}</h1>
+				{&self.body}
+				{&self.footer}
+			</article>
+		}
+	}
+}
+
+struct Highlighted(&'static str);
+
+impl Component for Highlighted {
+	fn to_render(&self, page: &mut Page) {
+		let class = scoped_css!("./scoped_css/test.css");
+		view! { <mark class={class}>{self.0}</mark> }
+	}
+}
+
+fn render(f: impl FnOnce(&mut Page)) -> String {
+	let mut page = Page::new();
+	f(&mut page);
+	page.into_html()
+}
+
+#[test]
+fn trusted_markup_is_not_escaped() {
+	let markup = Markup::trusted("<em>trusted</em>");
+	let html = render(|page| {
+		view! { <p>{markup}</p> }
+	});
+	assert_eq!(html, "<p><em>trusted</em></p>");
+}
+
+#[test]
+fn strings_are_still_escaped() {
+	let text = String::from("<em>untrusted</em>");
+	let html = render(|page| {
+		view! { <p>{text}</p> }
+	});
+	assert_eq!(html, "<p>&lt;em&gt;untrusted&lt;/em&gt;</p>");
+}
+
+#[test]
+fn capture_returns_rendered_view_without_adding_it() {
+	let mut page = Page::new();
+	page.push_static("<main>");
+	let captured = page.capture(|page| {
+		view! { <b>{"Tom & Jerry"}</b> }
+	});
+	page.push_static("</main>");
+
+	assert_eq!(captured.as_str(), "<b>Tom &amp; Jerry</b>");
+	assert_eq!(page.into_html(), "<main></main>");
+}
+
+#[test]
+fn markup_passed_as_prop() {
+	let html = render(|page| {
+		let body = page.capture(|page| {
+			view! { <p>{"<Hello>"}</p> }
+		});
+		view! { <Article title="News" body={body} /> }
+	});
+	assert_eq!(html, "<article><h1>News</h1><p>&lt;Hello&gt;</p></article>");
+}
+
+#[test]
+fn component_rendered_to_markup_keeps_head_elements() {
+	let mut page = Page::new();
+	let footer = Highlighted("end").to_markup(&mut page);
+	let body = Markup::trusted("<p>Body</p>");
+	{
+		let page = &mut page;
+		view! { <Article title="News" body={body} footer={Some(footer)} /> }
+	}
+
+	assert!(!page.head.is_empty());
+	let html = page.into_html();
+	assert!(html.starts_with("<article><h1>News</h1><p>Body</p><mark class=\""));
+	assert!(html.ends_with("\" >end</mark></article>"));
+}
@@ -1,6 +1,6 @@
 #![cfg(not(feature = "i18n"))]
 
-use tidos::{head, page, Component, Page};
+use tidos::{head, page, Component, Page, Props};
 
 #[test]
 fn renders_basic_html() {
@@ -36,6 +36,7 @@ fn renders_expression() {
 
 #[test]
 fn renders_component() {
+	#[derive(Props)]
 	struct Greeting {
 		pub name: String,
 	}
@@ -87,6 +88,16 @@ fn head_multiple_different_invocations_both_inject() {
 	assert!(page_output.head.contains("<title>My Page</title>"));
 }
 
+#[test]
+fn head_same_contents_at_different_call_sites_both_inject() {
+	let mut page_output = Page::new();
+	let page = &mut page_output;
+	head! { <meta name="robots" content="noindex" /> }
+	head! { <meta name="robots" content="noindex" /> }
+	assert_eq!(page_output.head.matches("<meta").count(), 2);
+	assert_eq!(page_output.head_ids.len(), 2);
+}
+
 #[test]
 fn head_with_expression() {
 	let mut page_output = Page::new();
@@ -98,6 +109,7 @@ fn head_with_expression() {
 
 #[test]
 fn head_injected_via_component_renders_on_page() {
+	#[derive(Props)]
 	struct TitleComponent {
 		pub title: String,
 	}
@@ -0,0 +1,185 @@
+#![cfg(not(feature = "i18n"))]
+
+use tidos::{view, Component, Page, Props};
+
+#[derive(Props)]
+struct Link {
+	#[prop(into)]
+	href: String,
+	label: &'static str,
+	#[prop(default = "_self")]
+	target: &'static str,
+	#[prop(optional)]
+	rank: usize,
+	title: Option<&'static str>,
+}
+
+impl Component for Link {
+	fn to_render(&self, page: &mut Page) {
+		view! {
+			<a href={&self.href} target={self.target} title={self.title} data-rank={self.rank.to_string()}>{self.label}</a>
+		}
+	}
+}
+
+fn render(f: impl FnOnce(&mut Page)) -> String {
+	let mut page = Page::new();
+	f(&mut page);
+	page.into_html()
+}
+
+#[test]
+fn optional_props_can_be_omitted() {
+	let html = render(|page| {
+		view! { <Link href="/news" label="News" /> }
+	});
+	assert_eq!(
+		html,
+		r#"<a href="/news" target="_self" data-rank="0" >News</a>"#
+	);
+}
+
+#[test]
+fn optional_props_can_be_set() {
+	let html = render(|page| {
+		view! { <Link href="/news" label="News" target="_blank" rank={2} title={Some("All news")} /> }
+	});
+	assert_eq!(
+		html,
+		r#"<a href="/news" target="_blank" title="All news" data-rank="2" >News</a>"#
+	);
+}
+
+#[test]
+fn string_variable_is_converted_into_string_prop() {
+	let href = String::from("/about");
+	let html = render(|page| {
+		view! { <Link href={href} label="About" /> }
+	});
+	assert_eq!(
+		html,
+		r#"<a href="/about" target="_self" data-rank="0" >About</a>"#
+	);
+}
+
+#[test]
+fn number_props_are_not_widened() {
+	let rank: u8 = 3;
+	let html = render(|page| {
+		view! { <Link href="/" label="Home" rank={usize::from(rank)} /> }
+	});
+	assert_eq!(
+		html,
+		r#"<a href="/" target="_self" data-rank="3" >Home</a>"#
+	);
+}
+
+#[test]
+fn builder_can_be_used_directly() {
+	let link = Link::builder().label("Home").href("/").rank(1).build();
+	assert_eq!(link.href, "/");
+	assert_eq!(link.target, "_self");
+	assert_eq!(link.rank, 1);
+	assert_eq!(link.title, None);
+}
+
+#[test]
+fn generic_props_are_inferred() {
+	#[derive(Props)]
+	struct Value<T: std::fmt::Display> {
+		value: T,
+		#[prop(default = "value")]
+		class: &'static str,
+	}
+
+	impl<T: std::fmt::Display> Component for Value<T> {
+		fn to_render(&self, page: &mut Page) {
+			view! {
+				<span class={self.class}>{self.value.to_string()}</span>
+			}
+		}
+	}
+
+	let html = render(|page| {
+		view! { <Value value={1.5} /> }
+	});
+	assert_eq!(html, r#"<span class="value" >1.5</span>"#);
+}
+
+#[test]
+fn attribute_values_are_converted_into_prop_types() {
+	use std::borrow::Cow;
+
+	#[derive(Props)]
+	struct Stat<'a> {
+		label: Cow<'a, str>,
+		value: u64,
+		ratio: f32,
+		offset: i16,
+		unit: Option<String>,
+		note: Option<&'a str>,
+	}
+
+	impl Component for Stat<'_> {
+		fn to_render(&self, page: &mut Page) {
+			view! {
+				<p title={self.note}>
+					{&self.label}{": {} {} {} {}", self.value, self.ratio, self.offset, self.unit.as_deref().unwrap_or("-")}
+				</p>
+			}
+		}
+	}
+
+	let label = "Visitors";
+	let unit = String::from("pp");
+	let html = render(|page| {
+		view! { <Stat label={label} value={1200} ratio={0.5} offset={-3} unit={unit} note="daily" /> }
+	});
+	assert_eq!(html, r#"<p title="daily" >Visitors: 1200 0.5 -3 pp</p>"#);
+}
+
+#[test]
+fn number_literal_fills_option_prop() {
+	#[derive(Props)]
+	struct Heading {
+		level: Option<u64>,
+	}
+
+	impl Component for Heading {
+		fn to_render(&self, page: &mut Page) {
+			view! { <h2 data-level={self.level.map(|level| level.to_string())}>{"News"}</h2> }
+		}
+	}
+
+	let html = render(|page| {
+		view! { <Heading level={2} /> }
+	});
+	assert_eq!(html, r#"<h2 data-level="2" >News</h2>"#);
+}
+
+#[test]
+fn reference_is_converted_into_owned_prop() {
+	#[derive(Props)]
+	struct Title {
+		title: String,
+	}
+
+	impl Component for Title {
+		fn to_render(&self, page: &mut Page) {
+			view! { <h1>{&self.title}</h1> }
+		}
+	}
+
+	let name = String::from("Ferris");
+	let html = render(|page| {
+		view! { <Title title={&name} /> }
+	});
+	assert_eq!(html, "<h1>Ferris</h1>");
+	assert_eq!(name, "Ferris");
+}
+
+#[test]
+fn invalid_props_are_rejected_at_compile_time() {
+	let t = trybuild::TestCases::new();
+	t.compile_fail("tests/props/panics/*.rs");
+}
@@ -0,0 +1,24 @@
+use tidos::{view, Component, Page, Props};
+
+struct Count(usize);
+
+#[derive(Props)]
+struct Counter {
+	count: Count,
+}
+
+impl Component for Counter {
+	fn to_render(&self, page: &mut Page) {
+		view! {
+			<p>{self.count.0.to_string()}</p>
+		}
+	}
+}
+
+fn main() {
+	let mut page = Page::new();
+	let page = &mut page;
+	view! {
+		<Counter count="three" />
+	}
+}
@@ -0,0 +1,23 @@
+use tidos::{view, Component, Page, Props};
+
+#[derive(Props)]
+struct Card {
+	title: String,
+	subtitle: Option<String>,
+}
+
+impl Component for Card {
+	fn to_render(&self, page: &mut Page) {
+		view! {
+			<h2>{&self.title}</h2>
+		}
+	}
+}
+
+fn main() {
+	let mut page = Page::new();
+	let page = &mut page;
+	view! {
+		<Card subtitle={None} />
+	}
+}
@@ -0,0 +1,9 @@
+use tidos::Props;
+
+#[derive(Props)]
+struct Card {
+	#[prop(required)]
+	title: String,
+}
+
+fn main() {}
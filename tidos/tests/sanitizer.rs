@@ -0,0 +1,103 @@
+#![cfg(not(feature = "i18n"))]
+
+use std::sync::LazyLock;
+use tidos::html::{clean, Policy};
+use tidos::{view, Page};
+
+fn render(f: impl FnOnce(&mut Page)) -> String {
+	let mut page = Page::new();
+	f(&mut page);
+	page.into_html()
+}
+
+#[test]
+fn scripts_are_removed_with_their_content() {
+	let markup = clean("<p>Hi</p><script>alert(1)</script><style>p{}</style>");
+	assert_eq!(markup.as_str(), "<p>Hi</p>");
+}
+
+#[test]
+fn event_handlers_are_removed() {
+	let markup = clean(r#"<p onclick="alert(1)" onmouseover="alert(2)">Hi</p>"#);
+	assert_eq!(markup.as_str(), "<p>Hi</p>");
+}
+
+#[test]
+fn javascript_urls_are_removed() {
+	let markup = clean(r#"<a href="javascript:alert(1)">a</a><a href="JaVaScRiPt:alert(1)">b</a>"#);
+	assert_eq!(
+		markup.as_str(),
+		r#"<a rel="noopener noreferrer nofollow">a</a><a rel="noopener noreferrer nofollow">b</a>"#
+	);
+}
+
+#[test]
+fn rich_text_is_kept() {
+	let markup = clean(
+		r#"<h2>Title</h2><p><strong>Bold</strong> <a href="https://example.com" title="Example">link</a></p><ul><li>One</li></ul>"#,
+	);
+	assert_eq!(
+		markup.as_str(),
+		r#"<h2>Title</h2><p><strong>Bold</strong> <a href="https://example.com" title="Example" rel="noopener noreferrer nofollow">link</a></p><ul><li>One</li></ul>"#
+	);
+}
+
+#[test]
+fn disallowed_tags_keep_their_text() {
+	let markup = clean("<form><button>Click</button></form>");
+	assert_eq!(markup.as_str(), "Click");
+}
+
+#[test]
+fn text_only_policy_strips_all_tags() {
+	let markup = Policy::text_only().clean("<p>Hello <b>world</b> &amp; more</p>");
+	assert_eq!(markup.as_str(), "Hello world &amp; more");
+}
+
+#[test]
+fn configured_policy() {
+	static POLICY: LazyLock<Policy> = LazyLock::new(|| {
+		Policy::rich_text()
+			.deny_tags(["img"])
+			.allow_tags(["details", "summary"])
+			.allow_attributes("code", ["class"])
+			.allow_url_schemes(["tel"])
+			.link_rel(None)
+	});
+
+	let markup = POLICY.clean(
+		r#"<img src="a.png"><details><summary>More</summary><code class="rust">fn</code></details><a href="tel:123">Call</a>"#,
+	);
+	assert_eq!(
+		markup.as_str(),
+		r#"<details><summary>More</summary><code class="rust">fn</code></details><a href="tel:123">Call</a>"#
+	);
+}
+
+#[test]
+fn sanitized_html_in_view() {
+	let comment =
+		String::from(r#"<p onclick="steal()">Nice <b>post</b>!</p><script>steal()</script>"#);
+	let html = render(|page| {
+		view! { <div>@sanitized_html{comment}</div> }
+	});
+	assert_eq!(html, "<div><p>Nice <b>post</b>!</p></div>");
+}
+
+#[test]
+fn sanitized_html_with_format_string() {
+	let name = "<i>Ann</i><script></script>";
+	let html = render(|page| {
+		view! { <div>@sanitized_html{"<p>{}</p>", name}</div> }
+	});
+	assert_eq!(html, "<div><p><i>Ann</i></p></div>");
+}
+
+#[test]
+fn policy_clean_in_view() {
+	let bio = "<h1>Me</h1><p>Hi</p>";
+	let html = render(|page| {
+		view! { <div>{Policy::text_only().clean(bio)}</div> }
+	});
+	assert_eq!(html, "<div>MeHi</div>");
+}
@@ -1,4 +1,58 @@
-use tidos::{scoped_css, Page};
+use tidos::{scoped_css, view, Component, Page, Props, Slot};
+
+#[derive(Props)]
+struct Panel<'a> {
+	pub body: Slot<'a>,
+}
+
+impl Component for Panel<'_> {
+	fn to_render(&self, page: &mut Page) {
+		view! {
+			<section><h2>{"Panel"}</h2>@slot{self.body}</section>
+		}
+	}
+}
+
+/// The id of the scope a view stamped on its elements.
+fn scope_id(html: &str) -> &str {
+	let start = html.find("class=\"tidos-").expect("a scoped class") + "class=\"".len();
+	let end = start + html[start..].find('"').unwrap();
+	&html[start..end]
+}
+
+/// Where a page's scoped CSS goes: a `<style>` per stylesheet in its head,
+/// or with `extract-css` the one stylesheet its head links to.
+#[cfg(not(feature = "extract-css"))]
+mod injected {
+	use tidos::Page;
+
+	pub const TAG: &str = "<style>";
+
+	pub fn css(page: &Page) -> &str {
+		&page.head
+	}
+
+	pub fn style(css: &str) -> String {
+		format!("<style>{css}</style>")
+	}
+}
+
+#[cfg(feature = "extract-css")]
+mod injected {
+	use tidos::{styles, Page};
+
+	pub const TAG: &str = "<link rel=\"stylesheet\"";
+
+	pub fn css(page: &Page) -> &'static str {
+		assert!(page.head.contains(styles::link()));
+		assert!(!page.head.contains("<style>"));
+		styles::stylesheet()
+	}
+
+	pub fn style(css: &str) -> String {
+		css.to_string()
+	}
+}
 
 #[cfg(not(feature = "i18n"))]
 #[test]
@@ -7,8 +61,21 @@ fn returns_scoped_class_name_and_injects_style() {
 	let page = &mut page_output;
 	let class = scoped_css!("./scoped_css/test.css");
 	assert!(class.starts_with("tidos-"));
-	assert!(page.head.contains(&format!("<style>.{class}")));
-	assert!(page.head.contains("color: red"));
+	assert_eq!(page.head.matches(injected::TAG).count(), 1);
+	let css = injected::css(page);
+	assert!(css.contains(&format!(".{class}")));
+	assert!(css.contains("color:red"));
+}
+
+#[cfg(not(feature = "i18n"))]
+#[test]
+fn class_is_hashed_from_the_stylesheet() {
+	let mut page_output = Page::new();
+	let page = &mut page_output;
+	let first = scoped_css!("./scoped_css/test.css");
+	let second = scoped_css!("./scoped_css/test.css");
+	assert_eq!(first, second);
+	assert_eq!(first, "tidos-test-cba69990");
 }
 
 #[cfg(not(feature = "i18n"))]
@@ -19,7 +86,254 @@ fn deduplicates_in_for_loop() {
 	for _ in 0..5 {
 		scoped_css!("./scoped_css/test.css");
 	}
-	assert_eq!(page.head.matches("<style>").count(), 1);
+	assert_eq!(page.head.matches(injected::TAG).count(), 1);
+}
+
+#[cfg(not(feature = "i18n"))]
+#[test]
+fn stamps_elements_of_the_view() {
+	let mut page_output = Page::new();
+	let page = &mut page_output;
+	view! {
+		<div class={scoped_css!("./scoped_css/card.css")}>
+			<h2>{"Title"}</h2>
+			{#if true}<p>{"Body"}</p>{/if}
+		</div>
+	};
+
+	let html = page_output.into_html();
+	let id = scope_id(&html);
+	assert_eq!(
+		html,
+		format!(
+			"<div class=\"{id}\" data-{id} ><h2 data-{id} >Title</h2><p data-{id} >Body</p></div>"
+		)
+	);
+}
+
+#[cfg(not(feature = "i18n"))]
+#[test]
+fn scopes_the_element_of_each_stylesheet() {
+	let mut page_output = Page::new();
+	let page = &mut page_output;
+	let test = scoped_css!("./scoped_css/test.css");
+	view! {
+		<main>
+			<div class={scoped_css!("./scoped_css/card.css")}>
+				<p class={scoped_css!("./scoped_css/test.css")}>{"Nested"}</p>
+			</div>
+			<p class={scoped_css!("./scoped_css/test.css")}><em>{"Note"}</em></p>
+		</main>
+	};
+
+	let card = scope_id(&page_output.template);
+	assert_eq!(
+		page_output.template,
+		format!(
+			"<main><div class=\"{card}\" data-{card} ><p class=\"{test}\" data-{card} data-{test} >Nested</p></div>\
+			<p class=\"{test}\" data-{test} ><em data-{test} >Note</em></p></main>"
+		)
+	);
+}
+
+#[cfg(not(feature = "i18n"))]
+#[test]
+fn rewrites_selectors() {
+	let mut page_output = Page::new();
+	let page = &mut page_output;
+	view! { <div class={scoped_css!("./scoped_css/card.css")}></div> };
+
+	let id = scope_id(&page_output.template).to_string();
+	let css = injected::css(&page_output);
+	assert!(css.contains(&format!(".{id}{{border-radius:8px}}")));
+	assert!(css.contains(&format!("h2[data-{id}],p[data-{id}]{{margin:0}}")));
+	assert!(css.contains(&format!("ul[data-{id}]>li.item:hover[data-{id}]::before{{")));
+	assert!(css.contains(&format!("body.dark h2[data-{id}]{{color:white}}")));
+	assert!(css.contains(&format!(
+		"@media (max-width:800px){{p[data-{id}]{{margin:1rem}}}}"
+	)));
+	assert!(css.contains(&format!("@keyframes {id}-spin{{")));
+	assert!(css.contains(&format!("animation:{id}-spin 1s linear infinite")));
+}
+
+#[cfg(not(feature = "i18n"))]
+#[test]
+fn styles_slot_content_but_not_child_components() {
+	let mut page_output = Page::new();
+	let page = &mut page_output;
+	view! {
+		<div class={scoped_css!("./scoped_css/card.css")}>
+			<Panel>{#slot:body}<p>{"Body"}</p>{/slot}</Panel>
+		</div>
+	};
+
+	let html = page_output.into_html();
+	let id = scope_id(&html);
+	assert_eq!(
+		html,
+		format!(
+			"<div class=\"{id}\" data-{id} ><section><h2>Panel</h2><p data-{id} >Body</p></section></div>"
+		)
+	);
+}
+
+#[cfg(not(feature = "i18n"))]
+#[test]
+fn formatted_class() {
+	let mut page_output = Page::new();
+	let page = &mut page_output;
+	view! { <div class={"rounded {}", scoped_css!("./scoped_css/card.css")}></div> };
+
+	assert_eq!(page_output.head.matches(injected::TAG).count(), 1);
+	let html = page_output.into_html();
+	let start = html.find("tidos-").unwrap();
+	let id = &html[start..start + html[start..].find('"').unwrap()];
+	assert_eq!(
+		html,
+		format!("<div class=\"rounded {id}\" data-{id} ></div>")
+	);
+}
+
+#[cfg(not(feature = "i18n"))]
+#[test]
+fn inline_tokens() {
+	let mut page_output = Page::new();
+	let page = &mut page_output;
+	view! {
+		<div class={scoped_css! {
+			& { padding: 1rem; }
+			h2:hover, h2 :focus-visible { color: #e94560; }
+		}}>
+			<h2>{"Title"}</h2>
+		</div>
+	};
+
+	let html = page_output.template.clone();
+	let id = scope_id(&html);
+	assert!(id.starts_with("tidos-scoped-css-"));
+	assert_eq!(
+		html,
+		format!("<div class=\"{id}\" data-{id} ><h2 data-{id} >Title</h2></div>")
+	);
+	assert!(injected::css(&page_output).contains(&injected::style(&format!(
+		".{id}{{padding:1rem}}h2:hover[data-{id}],h2[data-{id}] :focus-visible[data-{id}]{{color:#e94560}}"
+	))));
+}
+
+#[cfg(not(feature = "i18n"))]
+#[test]
+fn inline_string() {
+	let mut page_output = Page::new();
+	let page = &mut page_output;
+	let class = scoped_css!(r#"& { font-family: 'Roboto'; }"#);
+	assert!(injected::css(&page_output).contains(&format!(".{class}{{font-family:'Roboto'}}")));
+}
+
+#[cfg(not(feature = "i18n"))]
+#[test]
+fn inline_string_without_a_block() {
+	let mut page_output = Page::new();
+	let page = &mut page_output;
+	scoped_css!(r#"@import url("theme.css");"#);
+	assert!(injected::css(&page_output).contains(&injected::style(r#"@import url("theme.css");"#)));
+}
+
+#[cfg(not(feature = "i18n"))]
+#[test]
+fn same_inline_css_shares_a_scope() {
+	let mut page_output = Page::new();
+	let page = &mut page_output;
+	let first = scoped_css! { & { color: red; } };
+	let second = scoped_css! { & { color: red; } };
+	assert_eq!(first, second);
+	assert_eq!(page_output.head.matches(injected::TAG).count(), 1);
+}
+
+#[cfg(not(feature = "i18n"))]
+#[test]
+fn minifies() {
+	let mut page_output = Page::new();
+	let page = &mut page_output;
+	let class = scoped_css!("./scoped_css/minify.css");
+	assert!(
+		injected::css(&page_output).contains(&injected::style(&format!(
+			".{class}{{margin:0 auto,.5rem;color:#fc0;background:rgba(0,0,0,.5)}}"
+		)))
+	);
+}
+
+/// `Tidos.toml` targets Safari 12 and Firefox 78.
+#[cfg(not(feature = "i18n"))]
+#[test]
+fn prefixes_for_targets() {
+	let mut page_output = Page::new();
+	let page = &mut page_output;
+	let class = scoped_css!("./scoped_css/prefix.css");
+	assert!(injected::css(&page_output).contains(&injected::style(&format!(
+		".{class}{{-webkit-user-select:none;user-select:none;position:-webkit-sticky;position:sticky;color:red}}"
+	))));
+}
+
+#[cfg(not(feature = "i18n"))]
+#[test]
+fn custom_properties() {
+	let mut page_output = Page::new();
+	let page = &mut page_output;
+	let pct = 42;
+	let accent_colour = String::from("#e94560");
+	for _ in 0..2 {
+		view! {
+			<div class={scoped_css!("./scoped_css/bar.css", progress = pct, accent_colour = &accent_colour)}></div>
+		};
+	}
+
+	let html = page_output.template.clone();
+	let id = scope_id(&html);
+	let bar = format!(
+		"<div class=\"{id}\" style=\"--progress:42;--accent-colour:#e94560;\" data-{id} ></div>"
+	);
+	assert_eq!(html, format!("{bar}{bar}"));
+	assert_eq!(page_output.head.matches(injected::TAG).count(), 1);
+}
+
+#[cfg(not(feature = "i18n"))]
+#[test]
+fn custom_properties_are_escaped() {
+	let mut page_output = Page::new();
+	let page = &mut page_output;
+	let accent = "red; } body { display: none\" onclick=\"alert(1)";
+	view! { <div class={scoped_css!("./scoped_css/bar.css", accent = accent)}></div> };
+
+	assert!(page_output.template.contains(
+		"style=\"--accent:red\\3b  \\7d  body \\7b  display\\3a  none\\22  onclick\\3d \\22 alert\\28 1\\29 ;\""
+	));
+}
+
+#[cfg(not(feature = "i18n"))]
+#[test]
+fn custom_properties_cannot_fetch_or_comment() {
+	let mut page_output = Page::new();
+	let page = &mut page_output;
+	let accent = "url(//x) /* ";
+	view! { <div class={scoped_css!("./scoped_css/bar.css", accent = accent)} style="color: red"></div> };
+
+	assert!(page_output
+		.template
+		.contains("style=\"--accent:url\\28 \\2f \\2f x\\29  \\2f \\2a  ;color: red\""));
+}
+
+#[cfg(not(feature = "i18n"))]
+#[test]
+fn custom_properties_join_the_style() {
+	let mut page_output = Page::new();
+	let page = &mut page_output;
+	let width = 120;
+	view! { <div class={scoped_css!("./scoped_css/bar.css", progress = 5)} style="height: 4px"></div> };
+	view! { <p class={scoped_css!("./scoped_css/bar.css", progress = 6)} style={"width: {}px", width}></p> };
+
+	let html = page_output.into_html();
+	assert!(html.contains("style=\"--progress:5;height: 4px\""));
+	assert!(html.contains("style=\"--progress:6;width: 120px\""));
 }
 
 #[test]
@@ -27,3 +341,29 @@ fn file_not_found() {
 	let t = trybuild::TestCases::new();
 	t.compile_fail("tests/scoped_css/panics/file_not_found.rs");
 }
+
+#[test]
+fn inline_syntax_errors() {
+	let t = trybuild::TestCases::new();
+	t.compile_fail("tests/scoped_css/panics/missing_value.rs");
+	t.compile_fail("tests/scoped_css/panics/unclosed_string.rs");
+	t.compile_fail("tests/scoped_css/panics/dangling_comma.rs");
+}
+
+#[test]
+fn file_syntax_errors() {
+	let t = trybuild::TestCases::new();
+	t.compile_fail("tests/scoped_css/panics/invalid_file.rs");
+}
+
+#[test]
+fn custom_properties_need_a_view() {
+	let t = trybuild::TestCases::new();
+	t.compile_fail("tests/scoped_css/panics/properties_outside_view.rs");
+}
+
+#[test]
+fn rules_need_a_view() {
+	let t = trybuild::TestCases::new();
+	t.compile_fail("tests/scoped_css/panics/rules_outside_view.rs");
+}
@@ -0,0 +1,13 @@
+use tidos::{scoped_css, Page};
+
+fn dangling_comma() {
+	let mut page_output = Page::new();
+	let page = &mut page_output;
+	let _class = scoped_css! {
+		h2, h3, {
+			margin: 0;
+		}
+	};
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use tidos::{scoped_css, Page};
+
+fn invalid_file() {
+	let mut page_output = Page::new();
+	let page = &mut page_output;
+	let _class = scoped_css!("../broken.css");
+}
+
+fn main() {}
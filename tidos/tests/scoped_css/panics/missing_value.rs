@@ -0,0 +1,12 @@
+use tidos::{scoped_css, Page};
+
+fn main() {
+	let mut page_output = Page::new();
+	let page = &mut page_output;
+	let _class = scoped_css! {
+		.title {
+			color: red;
+			font-weight;
+		}
+	};
+}
@@ -0,0 +1,9 @@
+use tidos::{scoped_css, Page};
+
+fn properties_outside_view() {
+	let mut page_output = Page::new();
+	let page = &mut page_output;
+	let _class = scoped_css!("../bar.css", progress = 50);
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use tidos::{scoped_css, Page};
+
+fn rules_outside_view() {
+	let mut page_output = Page::new();
+	let page = &mut page_output;
+	let _class = scoped_css!("../card.css");
+}
+
+fn main() {}
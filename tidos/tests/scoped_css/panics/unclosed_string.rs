@@ -0,0 +1,11 @@
+use tidos::{scoped_css, Page};
+
+fn main() {
+	let mut page_output = Page::new();
+	let page = &mut page_output;
+	let _class = scoped_css!(r#"
+		.title {
+			content: "unclosed;
+		}
+	"#);
+}
@@ -0,0 +1,51 @@
+use tidos::{scoped_scss, view, Page};
+
+#[cfg(not(feature = "i18n"))]
+#[test]
+fn compiles_and_scopes() {
+	let mut page_output = Page::new();
+	let page = &mut page_output;
+	view! {
+		<div class={scoped_scss!("./scoped_scss/card.scss")}>
+			<h2>{"Title"}</h2>
+		</div>
+	};
+
+	let html = page_output.template.clone();
+	let start = html.find("tidos-card-").unwrap();
+	let id = &html[start..start + html[start..].find('"').unwrap()];
+	assert_eq!(
+		html,
+		format!("<div class=\"{id}\" data-{id} ><h2 data-{id} >Title</h2></div>")
+	);
+	#[cfg(not(feature = "extract-css"))]
+	assert_eq!(
+		page_output.head,
+		format!(
+			"<style>.{id}{{border-radius:8px;overflow:hidden;padding:1rem}}\
+			.{id} h2[data-{id}]{{color:#e94560}}\
+			.{id} h2:hover[data-{id}]{{color:#e01b3b}}\
+			body.dark h2[data-{id}]{{color:white}}</style>"
+		)
+	);
+}
+
+#[cfg(not(feature = "i18n"))]
+#[test]
+fn same_file_shares_a_scope() {
+	let mut page_output = Page::new();
+	let page = &mut page_output;
+	view! { <div class={scoped_scss!("./scoped_scss/card.scss")}></div> };
+	view! { <div class={scoped_scss!("./scoped_scss/card.scss")}></div> };
+
+	let html = page_output.into_html();
+	let ids = html.split('"').skip(1).step_by(2).collect::<Vec<_>>();
+	assert_eq!(ids[0], ids[1]);
+	assert!(ids[0].starts_with("tidos-card-"));
+}
+
+#[test]
+fn syntax_errors() {
+	let t = trybuild::TestCases::new();
+	t.compile_fail("tests/scoped_scss/panics/syntax_error.rs");
+}
@@ -0,0 +1,9 @@
+use tidos::{scoped_scss, Page};
+
+fn syntax_error() {
+	let mut page_output = Page::new();
+	let page = &mut page_output;
+	let _class = scoped_scss!("../broken.scss");
+}
+
+fn main() {}
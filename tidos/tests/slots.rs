@@ -1,5 +1,6 @@
-use tidos::{view, Component, Page, Slot};
+use tidos::{view, Component, Page, Props, Slot, SlotWith};
 
+#[derive(Props)]
 struct Card<'a> {
 	pub header: Slot<'a>,
 	pub body: Slot<'a>,
@@ -16,6 +17,7 @@ impl Component for Card<'_> {
 	}
 }
 
+#[derive(Props)]
 struct AnonymousCard<'a>(Slot<'a>);
 
 impl Component for AnonymousCard<'_> {
@@ -89,6 +91,7 @@ fn slot_with_empty_content() {
 #[cfg(not(feature = "i18n"))]
 #[test]
 fn slot_alongside_prop() {
+	#[derive(Props)]
 	struct Banner<'a> {
 		pub title: &'static str,
 		pub content: Slot<'a>,
@@ -119,3 +122,285 @@ fn slot_alongside_prop() {
 		"<section><h1>My Banner</h1><div><p>Slot content</p></div></section>"
 	);
 }
+
+#[cfg(not(feature = "i18n"))]
+#[test]
+fn unnamed_slot() {
+	let mut page_output = Page::new();
+	let page = &mut page_output;
+
+	view! {
+		<AnonymousCard>
+			<p>{"Content"}</p>
+		</AnonymousCard>
+	};
+
+	assert_eq!(page_output.into_html(), "<div><p>Content</p></div>");
+}
+
+#[derive(Props)]
+struct List<'a, T> {
+	pub items: &'a [T],
+	pub row: SlotWith<'a, T>,
+}
+
+impl<T> Component for List<'_, T> {
+	fn to_render(&self, page: &mut Page) {
+		view! {
+			<ul>
+				{#for item in self.items}
+					<li>@slot{self.row, item}</li>
+				{/for}
+			</ul>
+		}
+	}
+}
+
+#[cfg(not(feature = "i18n"))]
+#[test]
+fn scoped_slot() {
+	let names = vec!["Bob", "Alice"];
+	let mut page_output = Page::new();
+	let page = &mut page_output;
+
+	view! {
+		<List items={&names}>
+			{#slot:row |name|}<strong>{name}</strong>{/slot}
+		</List>
+	};
+
+	assert_eq!(
+		page_output.into_html(),
+		"<ul><li><strong>Bob</strong></li><li><strong>Alice</strong></li></ul>"
+	);
+}
+
+#[cfg(not(feature = "i18n"))]
+#[test]
+fn scoped_slot_with_pattern_and_captured_value() {
+	let scores = vec![("Bob", 3), ("Alice", 5)];
+	let unit = "pts";
+	let mut page_output = Page::new();
+	let page = &mut page_output;
+
+	view! {
+		<List items={&scores}>
+			{#slot:row |(name, score)|}{"{}: {} {}", name, score, unit}{/slot}
+		</List>
+	};
+
+	assert_eq!(
+		page_output.into_html(),
+		"<ul><li>Bob: 3 pts</li><li>Alice: 5 pts</li></ul>"
+	);
+}
+
+#[cfg(not(feature = "i18n"))]
+#[test]
+fn scoped_slot_alongside_named_slot() {
+	#[derive(Props)]
+	struct Table<'a, T> {
+		pub items: &'a [T],
+		pub header: Slot<'a>,
+		pub row: SlotWith<'a, T>,
+	}
+
+	impl<T> Component for Table<'_, T> {
+		fn to_render(&self, page: &mut Page) {
+			view! {
+				<table>
+					<thead>@slot{self.header}</thead>
+					<tbody>
+						{#for item in self.items}
+							<tr>@slot{self.row, item}</tr>
+						{/for}
+					</tbody>
+				</table>
+			}
+		}
+	}
+
+	struct User {
+		name: String,
+	}
+
+	let users = vec![User {
+		name: String::from("Bob"),
+	}];
+	let mut page_output = Page::new();
+	let page = &mut page_output;
+
+	view! {
+		<Table items={&users}>
+			{#slot:header}<tr><th>{"Name"}</th></tr>{/slot}
+			{#slot:row |user: &User|}<td>{&user.name}</td>{/slot}
+		</Table>
+	};
+
+	assert_eq!(
+		page_output.into_html(),
+		"<table><thead><tr><th>Name</th></tr></thead><tbody><tr><td>Bob</td></tr></tbody></table>"
+	);
+}
+
+#[derive(Props)]
+struct Panel<'a> {
+	pub title: &'static str,
+	pub footer: Option<Slot<'a>>,
+}
+
+impl Component for Panel<'_> {
+	fn to_render(&self, page: &mut Page) {
+		view! {
+			<section>
+				<h2>{self.title}</h2>
+				<footer>@slot{self.footer}{:else}<p>{"Default footer"}</p>{/slot}</footer>
+			</section>
+		}
+	}
+}
+
+#[cfg(not(feature = "i18n"))]
+#[test]
+fn optional_slot_is_filled() {
+	let mut page_output = Page::new();
+	let page = &mut page_output;
+
+	view! {
+		<Panel title="Filled">
+			{#slot:footer}<p>{"Custom footer"}</p>{/slot}
+		</Panel>
+	};
+
+	assert_eq!(
+		page_output.into_html(),
+		"<section><h2>Filled</h2><footer><p>Custom footer</p></footer></section>"
+	);
+}
+
+#[cfg(not(feature = "i18n"))]
+#[test]
+fn optional_slot_renders_fallback_when_omitted() {
+	let mut page_output = Page::new();
+	let page = &mut page_output;
+
+	view! {
+		<Panel title="Empty" />
+	};
+
+	assert_eq!(
+		page_output.into_html(),
+		"<section><h2>Empty</h2><footer><p>Default footer</p></footer></section>"
+	);
+}
+
+#[test]
+fn optional_slot_defaults_to_none() {
+	let panel = Panel::builder().title("Empty").build();
+
+	assert!(panel.footer.is_none());
+}
+
+#[cfg(not(feature = "i18n"))]
+#[test]
+fn slot_fallback_inside_if_with_else() {
+	#[derive(Props)]
+	struct Card<'a> {
+		pub open: bool,
+		pub body: Option<Slot<'a>>,
+	}
+
+	impl Component for Card<'_> {
+		fn to_render(&self, page: &mut Page) {
+			view! {
+				{#if self.open}
+					@slot{self.body}{:else}<p>{"Empty"}</p>{/slot}
+				{:else}
+					<p>{"Closed"}</p>
+				{/if}
+			}
+		}
+	}
+
+	let mut page_output = Page::new();
+	let page = &mut page_output;
+
+	view! {
+		<Card :open={true}>
+			{#slot:body}<p>{"Open"}</p>{/slot}
+		</Card>
+		<Card :open={true} />
+		<Card :open={false} />
+	};
+
+	assert_eq!(
+		page_output.into_html(),
+		"<p>Open</p><p>Empty</p><p>Closed</p>"
+	);
+}
+
+#[cfg(not(feature = "i18n"))]
+#[test]
+fn optional_slot_without_fallback_renders_nothing() {
+	#[derive(Props)]
+	struct Aside<'a>(Option<Slot<'a>>);
+
+	impl Component for Aside<'_> {
+		fn to_render(&self, page: &mut Page) {
+			view! {
+				<aside>@slot{self.0}</aside>
+			}
+		}
+	}
+
+	let mut page_output = Page::new();
+	let page = &mut page_output;
+
+	view! {
+		<Aside>
+			<p>{"Note"}</p>
+		</Aside>
+	};
+	Aside(None).to_render(page);
+
+	assert_eq!(
+		page_output.into_html(),
+		"<aside><p>Note</p></aside><aside></aside>"
+	);
+}
+
+#[cfg(not(feature = "i18n"))]
+#[test]
+fn slot_inside_if_is_not_mistaken_for_fallback() {
+	#[derive(Props)]
+	struct Toggle<'a> {
+		pub open: bool,
+		pub body: Slot<'a>,
+	}
+
+	impl Component for Toggle<'_> {
+		fn to_render(&self, page: &mut Page) {
+			view! {
+				{#if self.open}
+					@slot{self.body}
+				{:else}
+					<p>{"Closed"}</p>
+				{/if}
+			}
+		}
+	}
+
+	let mut page_output = Page::new();
+	let page = &mut page_output;
+
+	view! {
+		<Toggle :open={true}>
+			{#slot:body}<p>{"Open"}</p>{/slot}
+		</Toggle>
+		<Toggle :open={false}>
+			{#slot:body}<p>{"Open"}</p>{/slot}
+		</Toggle>
+	};
+
+	assert_eq!(page_output.into_html(), "<p>Open</p><p>Closed</p>");
+}
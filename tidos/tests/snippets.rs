@@ -0,0 +1,131 @@
+use tidos::{view, Component, Page, Props, Slot};
+
+fn render(f: impl FnOnce(&mut Page)) -> String {
+	let mut p = Page::new();
+	f(&mut p);
+	p.into_html()
+}
+
+struct User {
+	name: &'static str,
+	email: &'static str,
+}
+
+#[test]
+fn snippet_with_parameter() {
+	let users = vec![
+		User {
+			name: "Bob",
+			email: "bob@example.com",
+		},
+		User {
+			name: "Alice",
+			email: "alice@example.com",
+		},
+	];
+
+	assert_eq!(
+		render(|page| {
+			view! {
+				{#snippet row(user: &User)}
+					<tr><td>{user.name}</td><td>{user.email}</td></tr>
+				{/snippet}
+				<table>
+					{#for user in &users}
+						@render{row(user)}
+					{/for}
+				</table>
+			}
+		}),
+		"<table><tr><td>Bob</td><td>bob@example.com</td></tr><tr><td>Alice</td><td>alice@example.com</td></tr></table>"
+	);
+}
+
+#[test]
+fn snippet_without_parameters() {
+	assert_eq!(
+		render(|page| {
+			view! {
+				{#snippet divider()}
+					<hr />
+				{/snippet}
+				<p>{"One"}</p>
+				@render{divider()}
+				<p>{"Two"}</p>
+				@render{divider()}
+			}
+		}),
+		"<p>One</p><hr/><p>Two</p><hr/>"
+	);
+}
+
+#[test]
+fn snippet_with_multiple_parameters_captures_environment() {
+	let currency = "€";
+
+	assert_eq!(
+		render(|page| {
+			view! {
+				{#snippet price(label: &str, cents: u32)}
+					<dt>{label}</dt>
+					<dd>{"{}{}.{:02}", currency, cents / 100, cents % 100}</dd>
+				{/snippet}
+				<dl>
+					@render{price("Coffee", 250)}
+					@render{price("Cake", 1199)}
+				</dl>
+			}
+		}),
+		"<dl><dt>Coffee</dt><dd>€2.50</dd><dt>Cake</dt><dd>€11.99</dd></dl>"
+	);
+}
+
+#[cfg(not(feature = "i18n"))]
+#[test]
+fn snippet_passed_as_slot() {
+	#[derive(Props)]
+	struct Card<'a> {
+		pub title: &'static str,
+		pub footer: Slot<'a>,
+	}
+
+	impl Component for Card<'_> {
+		fn to_render(&self, page: &mut Page) {
+			view! {
+				<article>
+					<h2>{self.title}</h2>
+					<footer>@slot{self.footer}</footer>
+				</article>
+			}
+		}
+	}
+
+	let mut page_output = Page::new();
+	let page = &mut page_output;
+
+	view! {
+		{#snippet footer()}
+			<a href="/more">{"Read more"}</a>
+		{/snippet}
+		<Card title="First" footer={Box::new(footer)} />
+		<Card title="Second" footer={Box::new(footer)} />
+	};
+
+	assert_eq!(
+		page_output.into_html(),
+		"<article><h2>First</h2><footer><a href=\"/more\" >Read more</a></footer></article>\
+		 <article><h2>Second</h2><footer><a href=\"/more\" >Read more</a></footer></article>"
+	);
+}
+
+#[test]
+fn snippet_missing_parameter_list() {
+	let t = trybuild::TestCases::new();
+	t.compile_fail("tests/control_tags/panics/snippet_missing_parameter_list.rs");
+}
+
+#[test]
+fn render_without_call() {
+	let t = trybuild::TestCases::new();
+	t.compile_fail("tests/control_tags/panics/render_without_call.rs");
+}
@@ -0,0 +1,141 @@
+#![cfg(not(feature = "i18n"))]
+
+use std::borrow::Cow;
+use std::net::Ipv4Addr;
+use std::rc::Rc;
+use std::sync::Arc;
+use tidos::{view, Fmt, Page, ToHtml};
+
+fn render(f: impl FnOnce(&mut Page)) -> String {
+	let mut page = Page::new();
+	f(&mut page);
+	page.into_html()
+}
+
+#[test]
+fn integers() {
+	let (a, b, c, d) = (42_u8, -7_i32, u64::MAX, i128::MIN);
+	let html = render(|page| {
+		view! { <p>{a}</p><p>{b}</p><p>{c}</p><p>{d}</p> }
+	});
+	assert_eq!(
+		html,
+		"<p>42</p><p>-7</p><p>18446744073709551615</p><p>-170141183460469231731687303715884105728</p>"
+	);
+}
+
+#[test]
+fn floats_match_display() {
+	let (a, b, c) = (0.5_f32, 1.0_f64, -2.25_f64);
+	let html = render(|page| {
+		view! { <p>{a}</p><p>{b}</p><p>{c}</p> }
+	});
+	assert_eq!(html, "<p>0.5</p><p>1</p><p>-2.25</p>");
+}
+
+#[test]
+fn chars_are_escaped() {
+	let (a, b) = ('é', '<');
+	let html = render(|page| {
+		view! { <p>{a}{b}</p> }
+	});
+	assert_eq!(html, "<p>é&lt;</p>");
+}
+
+#[test]
+fn bools() {
+	let html = render(|page| {
+		view! { <p>{true}{" "}{false}</p> }
+	});
+	assert_eq!(html, "<p>true false</p>");
+}
+
+#[test]
+fn options_render_nothing_when_none() {
+	let (some, none): (Option<u32>, Option<&str>) = (Some(3), None);
+	let html = render(|page| {
+		view! { <p>{some}</p><p>{none}</p> }
+	});
+	assert_eq!(html, "<p>3</p><p></p>");
+}
+
+#[test]
+fn strings_and_cows_are_escaped() {
+	let owned = String::from("Tom & Jerry");
+	let cow: Cow<str> = Cow::Borrowed("\"quoted\"");
+	let html = render(|page| {
+		view! { <p>{owned}</p><p>{cow}</p> }
+	});
+	assert_eq!(html, "<p>Tom &amp; Jerry</p><p>&quot;quoted&quot;</p>");
+}
+
+#[test]
+fn display_values_through_fmt() {
+	struct Tag;
+
+	impl std::fmt::Display for Tag {
+		fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+			f.write_str("<b>")
+		}
+	}
+
+	let address = Ipv4Addr::LOCALHOST;
+	let html = render(|page| {
+		view! { <p>{Fmt(address)}</p><p>{Fmt(Tag)}</p> }
+	});
+	assert_eq!(html, "<p>127.0.0.1</p><p>&lt;b&gt;</p>");
+}
+
+#[test]
+fn references_render_their_value() {
+	let count = &&12_usize;
+	let html = render(|page| {
+		view! { <p>{count}</p> }
+	});
+	assert_eq!(html, "<p>12</p>");
+}
+
+#[test]
+fn custom_implementation() {
+	struct Price(u32);
+
+	impl ToHtml for Price {
+		fn to_html(&self, page: &mut Page) {
+			self.0.to_html(page);
+			page.push_static(" EUR");
+		}
+	}
+
+	let price = Price(15);
+	let html = render(|page| {
+		view! { <p>{price}</p> }
+	});
+	assert_eq!(html, "<p>15 EUR</p>");
+}
+
+#[test]
+fn string_types_render_through_as_ref() {
+	struct Username(String);
+
+	impl AsRef<str> for Username {
+		fn as_ref(&self) -> &str {
+			&self.0
+		}
+	}
+
+	let rc: Rc<str> = Rc::from("<rc>");
+	let arc: Arc<str> = Arc::from("arc");
+	let user = Username(String::from("Tom & Jerry"));
+	let html = render(|page| {
+		view! { <p>{rc}{arc}{user}</p> }
+	});
+	assert_eq!(html, "<p>&lt;rc&gt;arcTom &amp; Jerry</p>");
+}
+
+// `Markdown` joins the types the compiler suggests, changing the snapshot.
+#[cfg(not(feature = "markdown"))]
+#[test]
+fn values_without_to_html_are_rejected_at_compile_time() {
+	let t = trybuild::TestCases::new();
+	t.compile_fail("tests/to_html/panics/*.rs");
+}
@@ -0,0 +1,10 @@
+use tidos::{view, Page};
+
+fn main() {
+	let tags = vec!["a", "b"];
+	let mut page = Page::new();
+	let page = &mut page;
+	view! {
+		<p>{tags}</p>
+	}
+}
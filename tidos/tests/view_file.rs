@@ -0,0 +1,66 @@
+#![cfg(not(feature = "i18n"))]
+
+use tidos::{view, view_file, Component, Page, Props, Slot};
+
+#[derive(Props)]
+struct Layout<'a> {
+	pub title: String,
+	pub items: Vec<&'static str>,
+	pub content: Slot<'a>,
+}
+
+impl Component for Layout<'_> {
+	fn to_render(&self, page: &mut Page) {
+		view_file!("./view_file/layout.tidos");
+	}
+}
+
+fn render(f: impl FnOnce(&mut Page)) -> String {
+	let mut page = Page::new();
+	f(&mut page);
+	page.into_html()
+}
+
+#[test]
+fn template_file_sees_self() {
+	let html = render(|page| {
+		view! {
+			<Layout title={String::from("Home")} items={vec!["a", "b"]}>
+				{#slot:content}
+					<p>{"Welcome"}</p>
+				{/slot}
+			</Layout>
+		}
+	});
+	assert_eq!(
+		html,
+		"<header><h1>Home</h1></header><ul><li>a</li><li>b</li></ul><main><p>Welcome</p></main>"
+	);
+}
+
+#[test]
+fn template_file_control_flow() {
+	let html = render(|page| {
+		view! {
+			<Layout title={String::from("Empty")} items={vec![]}>
+				{#slot:content}
+					<p>{"Nothing"}</p>
+				{/slot}
+			</Layout>
+		}
+	});
+	assert!(html.contains("<ul><li>Nothing here</li></ul>"));
+}
+
+#[test]
+fn template_file_sees_local_variables() {
+	let name = "<Alice>";
+	let html = render(|page| view_file!("./view_file/greeting.tidos"));
+	assert_eq!(html, "<p>Hello, &lt;Alice&gt;</p>");
+}
+
+#[test]
+fn invalid_template_files_are_rejected_at_compile_time() {
+	let t = trybuild::TestCases::new();
+	t.compile_fail("tests/view_file/panics/*.rs");
+}
@@ -0,0 +1,7 @@
+use tidos::{view_file, Page};
+
+fn main() {
+	let mut page = Page::new();
+	let page = &mut page;
+	view_file!("./unclosed_tag.tidos");
+}
@@ -9,7 +9,7 @@ fn render(f: impl FnOnce(&mut Page)) -> String {
 #[test]
 fn empty_body() {
 	assert_eq!(
-		render(|page| {
+		render(|_page| {
 			view! {}
 		}),
 		""
@@ -0,0 +1,110 @@
+use crate::id::crate_relative;
+use crate::source_file::SourceFile;
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens, TokenStreamExt};
+use std::path::Path;
+use syn::parse::{Parse, ParseStream};
+use syn::LitStr;
+use xxhash_rust::xxh3::xxh3_64;
+
+/// `asset!("../public/logo.svg")`, a static file embedded in the binary and
+/// served at a URL containing a hash of its contents.
+pub struct Asset {
+	/// The file's path relative to the calling crate, the manifest's key.
+	source: String,
+	/// The absolute path, for `include_bytes!`.
+	path: String,
+	/// The fingerprinted URL, e.g. `/assets/logo.3fa1c2d0.svg`.
+	url: String,
+	content_type: String,
+}
+
+impl Parse for Asset {
+	fn parse(input: ParseStream) -> syn::Result<Self> {
+		let literal = input.parse::<LitStr>().map_err(|error| {
+			syn::Error::new(
+				error.span(),
+				"expected the path to a file, e.g. `asset!(\"../public/logo.svg\")`",
+			)
+		})?;
+		input.parse::<Option<syn::Token![,]>>()?;
+		if !input.is_empty() {
+			return Err(input.error("unexpected tokens after the path"));
+		}
+
+		let path = SourceFile::resolve(&literal)?;
+		let bytes = std::fs::read(&path).map_err(|error| {
+			syn::Error::new(
+				literal.span(),
+				format!("couldn't read `{}`: {error}", path.display()),
+			)
+		})?;
+
+		Ok(Asset {
+			source: crate_relative(&path),
+			url: url(&path, &bytes),
+			content_type: content_type(&path),
+			path: path.to_string_lossy().into_owned(),
+		})
+	}
+}
+
+impl ToTokens for Asset {
+	fn to_tokens(&self, tokens: &mut TokenStream) {
+		let Asset {
+			source,
+			path,
+			url,
+			content_type,
+		} = self;
+		tokens.append_all(quote! {
+			{
+				tidos::internals::inventory::submit! {
+					tidos::assets::Asset {
+						source: #source,
+						url: #url,
+						content_type: #content_type,
+						bytes: include_bytes!(#path),
+					}
+				}
+				#url
+			}
+		});
+	}
+}
+
+/// `/assets/<name>.<hash>.<extension>`, with the hash of the contents so the
+/// URL changes whenever the file does.
+fn url(path: &Path, bytes: &[u8]) -> String {
+	let hash = xxh3_64(bytes) as u32;
+	let name = path
+		.file_stem()
+		.map(|stem| stem.to_string_lossy())
+		.unwrap_or_default()
+		.chars()
+		.map(|char| {
+			if char.is_ascii_alphanumeric() || matches!(char, '-' | '_' | '.') {
+				char
+			} else {
+				'-'
+			}
+		})
+		.collect::<String>();
+	match path.extension() {
+		Some(extension) => format!(
+			"/assets/{name}.{hash:08x}.{}",
+			extension.to_string_lossy().to_ascii_lowercase()
+		),
+		None => format!("/assets/{name}.{hash:08x}"),
+	}
+}
+
+/// The `Content-Type` to serve the file with, guessed from its extension.
+fn content_type(path: &Path) -> String {
+	let mime = mime_guess::from_path(path).first_or_octet_stream();
+	if mime.type_() == mime_guess::mime::TEXT || mime.essence_str() == "application/javascript" {
+		format!("{}; charset=utf-8", mime.essence_str())
+	} else {
+		mime.essence_str().to_string()
+	}
+}
@@ -1,3 +1,4 @@
+use crate::code_generation::component::attribute_value_to_tokens;
 use crate::tokens::{Attribute, AttributeType, TextContent};
 use proc_macro2::{Ident, TokenStream};
 use quote::{format_ident, quote, ToTokens, TokenStreamExt};
@@ -35,6 +36,16 @@ impl ToTokens for Attribute {
 					page.push_static(concat!(#attribute_name, "=\"", #literal, "\" "));
 				});
 			}
+			AttributeType::Expression {
+				content: TextContent::Expression(expr),
+			} => {
+				let attribute_name = name
+					.clone()
+					.to_string()
+					.trim_start_matches("r#")
+					.to_string();
+				tokens.append_all(attribute_value_to_tokens(expr, &attribute_name));
+			}
 			AttributeType::Expression { content } => {
 				let attribute_name = name
 					.clone()
@@ -52,32 +63,22 @@ impl ToTokens for Attribute {
 }
 
 impl Attribute {
-	pub fn to_tokens_custom_element(&self) -> TokenStream {
-		let name = Ident::new(&self.name, self.name_span).to_token_stream();
-		match &self.attribute_type {
-			AttributeType::ImplicitToggle => {
-				quote! { #name }
-			}
-			AttributeType::ExplicitToggle { value } => {
-				quote! { #name: #value }
-			}
-			AttributeType::Constant => {
-				quote! { #name: true }
-			}
-			AttributeType::ConstantLiteral { literal } => {
-				quote! { #name: #literal }
-			}
+	/// The prop name and value this attribute passes to a component.
+	pub fn to_prop_tokens(&self) -> (Ident, TokenStream) {
+		let name = Ident::new(&self.name, self.name_span);
+		let value = match &self.attribute_type {
+			AttributeType::ImplicitToggle => name.to_token_stream(),
+			AttributeType::ExplicitToggle { value } => value.to_token_stream(),
+			AttributeType::Constant => quote! { true },
+			AttributeType::ConstantLiteral { literal } => literal.to_token_stream(),
 			AttributeType::Expression { content } => match content {
-				TextContent::Literal(literal) => {
-					quote! { #name: #literal }
-				}
+				TextContent::Literal(literal) => literal.to_token_stream(),
 				TextContent::Formatted(literal, contents) => {
-					quote! { #name: format!(#literal #( , #( #contents )* )* ) }
-				}
-				TextContent::Expression(expr) => {
-					quote! { #name: #( #expr )* }
+					quote! { format!(#literal #( , #( #contents )* )* ) }
 				}
+				TextContent::Expression(expr) => quote! { #( #expr )* },
 			},
-		}
+		};
+		(name, value)
 	}
 }
@@ -1,9 +1,12 @@
 use crate::tokens::{Attribute, AttributeType, Component, Content, HTMLTag, TextContent};
-use proc_macro2::TokenStream;
-use quote::{format_ident, quote, ToTokens, TokenStreamExt};
+use proc_macro2::{Ident, Span, TokenStream, TokenTree};
+use quote::{format_ident, quote, quote_spanned, ToTokens, TokenStreamExt};
 
 impl ToTokens for Component {
 	fn to_tokens(&self, tokens: &mut TokenStream) {
+		for scope in &self.scopes {
+			scope.head_tokens().to_tokens(tokens);
+		}
 		to_push_stmts(&self.children).to_tokens(tokens);
 	}
 }
@@ -46,9 +49,21 @@ fn process_content(child: &Content, flat_args: &mut Vec<TokenStream>, result: &m
 			flush_flat(flat_args, result);
 			ct.to_tokens(result);
 		}
-		Content::SlotRender(expr) => {
+		Content::SlotRender {
+			slot,
+			args,
+			fallback,
+		} => {
+			flush_flat(flat_args, result);
+			result.append_all(slot_render_to_tokens(slot, args, fallback));
+		}
+		Content::SnippetRender { callee, args } => {
+			flush_flat(flat_args, result);
+			result.append_all(snippet_call_to_tokens(callee, args));
+		}
+		Content::ComponentRender { component } => {
 			flush_flat(flat_args, result);
-			result.append_all(quote! { (#( #expr )*)(page); });
+			result.append_all(component_render_to_tokens(component));
 		}
 		Content::Tag(tag) if tag.is_component() => {
 			flush_flat(flat_args, result);
@@ -63,9 +78,66 @@ fn process_content(child: &Content, flat_args: &mut Vec<TokenStream>, result: &m
 		Content::RawHTMLExpression(text) => {
 			raw_to_args(text, flat_args);
 		}
+		Content::SanitizedHTMLExpression(text) => {
+			let markup = cleaned_to_tokens(quote!(tidos::html::clean), text);
+			flat_args.push(quote! { tidos::internals::text(&#markup) });
+		}
+		Content::MarkdownExpression(text) => {
+			let markup = cleaned_to_tokens(quote!(tidos::markdown::render), text);
+			flat_args.push(quote! { tidos::internals::text(&#markup) });
+		}
+	}
+}
+
+/// Call a slot closure, passing the argument of a scoped slot after `page`.
+///
+/// Plain slots dispatch through `RenderSlot`, so an `Option<Slot>` that is
+/// `None` renders nothing. With a fallback the slot must be an `Option`, and
+/// the fallback is rendered when it is `None`.
+pub(crate) fn slot_render_to_tokens(
+	slot: &[TokenTree],
+	args: &[TokenTree],
+	fallback: &Option<Vec<Content>>,
+) -> TokenStream {
+	let args = if args.is_empty() {
+		quote! {}
+	} else {
+		quote! { , #( #args )* }
+	};
+
+	if let Some(fallback) = fallback {
+		let fallback = to_push_stmts(fallback);
+		quote! {
+			match ::std::option::Option::as_ref(&(#( #slot )*)) {
+				::std::option::Option::Some(slot) => slot(page #args),
+				::std::option::Option::None => { #fallback }
+			}
+		}
+	} else if args.is_empty() {
+		quote! { tidos::internals::RenderSlot::render_slot(&(#( #slot )*), page); }
+	} else {
+		quote! { (#( #slot )*)(page #args); }
+	}
+}
+
+/// Call a snippet closure declared with `{#snippet}`, passing `page` as the
+/// first argument ahead of the caller's own arguments.
+pub(crate) fn snippet_call_to_tokens(callee: &Ident, args: &TokenStream) -> TokenStream {
+	if args.is_empty() {
+		quote! { #callee(page); }
+	} else {
+		quote! { #callee(page, #args); }
 	}
 }
 
+/// Render a value implementing `Component`, which includes references,
+/// `Option`s and collections of components.
+pub(crate) fn component_render_to_tokens(component: &[TokenTree]) -> TokenStream {
+	// Spanned at the expression so a value that isn't a component is reported there.
+	let span = component[0].span();
+	quote_spanned! { span=> tidos::Component::to_render(&(#( #component )*), page); }
+}
+
 /// Expand a native HTML tag inline — tag open, attributes, children, tag close
 /// are all added to `flat_args` so the caller's `combine!` can merge adjacent
 /// literals across tag boundaries.
@@ -103,6 +175,8 @@ pub(crate) fn process_native_tag(
 /// Contribute an attribute's value(s) to `flat_args`.
 /// Toggle attributes (ImplicitToggle / ExplicitToggle) are always boundaries:
 /// they flush `flat_args` and emit a direct `if … { page.push_static(…); }`.
+/// Expression attributes are boundaries too: they dispatch through
+/// `AttributeValue`, which may omit the attribute (e.g. `None` or `false`).
 fn process_attribute(attr: &Attribute, flat_args: &mut Vec<TokenStream>, result: &mut TokenStream) {
 	let name = attr.name.as_str();
 	let name_trimmed = name.trim_start_matches("r#");
@@ -118,6 +192,12 @@ fn process_attribute(attr: &Attribute, flat_args: &mut Vec<TokenStream>, result:
 			flat_args.push(quote! { #literal });
 			flat_args.push(quote! { "\" " });
 		}
+		AttributeType::Expression {
+			content: TextContent::Expression(expr),
+		} => {
+			flush_flat(flat_args, result);
+			result.append_all(attribute_value_to_tokens(expr, name_trimmed));
+		}
 		AttributeType::Expression { content } => {
 			flat_args.push(quote! { #name_trimmed });
 			flat_args.push(quote! { "=\"" });
@@ -140,6 +220,19 @@ fn process_attribute(attr: &Attribute, flat_args: &mut Vec<TokenStream>, result:
 	}
 }
 
+/// Render `name={expr}` through `AttributeValue`, or `AsRef<str>` for other
+/// string types, see `tidos::internals::Interpolated`.
+pub(crate) fn attribute_value_to_tokens(expr: &[TokenTree], name: &str) -> TokenStream {
+	let span = expr.first().map_or_else(Span::call_site, TokenTree::span);
+	quote_spanned! { span=>
+		{
+			#[allow(unused_imports)]
+			use tidos::internals::{AttributeFallback as _, AttributeViaAsRef as _, AttributeViaValue as _};
+			(&&&tidos::internals::Interpolated(&( #( #expr )* ))).attribute(#name, page);
+		}
+	}
+}
+
 fn text_to_args(text: &TextContent, flat_args: &mut Vec<TokenStream>) {
 	match text {
 		TextContent::Literal(literal) => {
@@ -151,7 +244,32 @@ fn text_to_args(text: &TextContent, flat_args: &mut Vec<TokenStream>) {
 			flat_args.push(quote! { format!(#lit #( , #( #contents )* )* ) });
 		}
 		TextContent::Expression(expr) => {
-			flat_args.push(quote! { tidos::sanitize!( #( #expr )* ) });
+			let span = expr.first().map_or_else(Span::call_site, TokenTree::span);
+			flat_args.push(quote_spanned! { span=>
+				{
+					#[allow(unused_imports)]
+					use tidos::internals::{TextFallback as _, TextViaAsRef as _, TextViaToHtml as _};
+					(&&&tidos::internals::Interpolated(&( #( #expr )* ))).text()
+				}
+			});
+		}
+	}
+}
+
+/// Pass untrusted input to `clean`, `tidos::html::clean` for
+/// `@sanitized_html{…}` or `tidos::markdown::render` for `@markdown{…}`, both
+/// of which return `Markup`.
+pub(crate) fn cleaned_to_tokens(clean: TokenStream, text: &TextContent) -> TokenStream {
+	match text {
+		TextContent::Literal(literal) => {
+			quote! { #clean(#literal) }
+		}
+		TextContent::Formatted(literal, contents) => {
+			quote! { #clean(format!(#literal #( , #( #contents )* )* )) }
+		}
+		TextContent::Expression(expr) => {
+			let span = expr.first().map_or_else(Span::call_site, TokenTree::span);
+			quote_spanned! { span=> #clean(&( #( #expr )* )) }
 		}
 	}
 }
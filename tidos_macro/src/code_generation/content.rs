@@ -1,3 +1,6 @@
+use crate::code_generation::component::{
+	cleaned_to_tokens, component_render_to_tokens, slot_render_to_tokens, snippet_call_to_tokens,
+};
 use crate::tokens::{Content, TextContent};
 use proc_macro2::{Literal, TokenStream};
 use quote::{quote, ToTokens};
@@ -9,8 +12,26 @@ impl ToTokens for Content {
 			Content::ControlTag(control_tag) => control_tag.to_tokens(tokens),
 			Content::Text(text_content) => text_content.to_tokens(tokens),
 			Content::RawHTMLExpression(text_content) => text_content.to_tokens_raw(tokens),
-			Content::SlotRender(expr) => {
-				quote! { (#( #expr )*)(page); }.to_tokens(tokens);
+			Content::SanitizedHTMLExpression(text_content) => {
+				let markup = cleaned_to_tokens(quote!(tidos::html::clean), text_content);
+				quote! { tidos::ToHtml::to_html(&#markup, page); }.to_tokens(tokens);
+			}
+			Content::MarkdownExpression(text_content) => {
+				let markup = cleaned_to_tokens(quote!(tidos::markdown::render), text_content);
+				quote! { tidos::ToHtml::to_html(&#markup, page); }.to_tokens(tokens);
+			}
+			Content::SlotRender {
+				slot,
+				args,
+				fallback,
+			} => {
+				slot_render_to_tokens(slot, args, fallback).to_tokens(tokens);
+			}
+			Content::SnippetRender { callee, args } => {
+				snippet_call_to_tokens(callee, args).to_tokens(tokens);
+			}
+			Content::ComponentRender { component } => {
+				component_render_to_tokens(component).to_tokens(tokens);
 			}
 		}
 	}
@@ -32,7 +53,7 @@ impl ToTokens for TextContent {
 			}
 			TextContent::Expression(expr) => {
 				quote! {
-					{ let _v = tidos::sanitize!( #( #expr )* ); page.push_dynamic(_v); }
+					tidos::ToHtml::to_html(&( #( #expr )* ), page);
 				}
 				.to_tokens(tokens);
 			}
@@ -1,6 +1,6 @@
 use crate::code_generation::component::to_push_stmts;
 use crate::tokens::{Content, ControlTag};
-use proc_macro2::{TokenStream, TokenTree};
+use proc_macro2::{Ident, TokenStream, TokenTree};
 use quote::{quote, ToTokens, TokenStreamExt};
 
 impl ToTokens for ControlTag {
@@ -23,9 +23,18 @@ impl ToTokens for ControlTag {
 			ControlTag::For {
 				left_side,
 				right_side,
+				loop_binding,
 				contents,
+				empty_contents,
 			} => {
-				ControlTag::to_tokens_for_loop(left_side, right_side, contents, tokens);
+				ControlTag::to_tokens_for_loop(
+					left_side,
+					right_side,
+					loop_binding,
+					contents,
+					empty_contents,
+					tokens,
+				);
 			}
 			ControlTag::Match {
 				match_statement,
@@ -34,22 +43,85 @@ impl ToTokens for ControlTag {
 				ControlTag::to_tokens_match(match_statement, cases, tokens);
 			}
 			ControlTag::Slot { .. } => unreachable!("Slot should not be rendered"),
+			ControlTag::Snippet {
+				name,
+				params,
+				contents,
+			} => {
+				let body = to_push_stmts(contents);
+				let params = if params.is_empty() {
+					quote! {}
+				} else {
+					quote! { , #params }
+				};
+				tokens.append_all(quote! {
+					let #name = |page: &mut tidos::Page #params| { #body };
+				});
+			}
+			ControlTag::Let { pattern, value } => {
+				tokens.append_all(quote! { let #( #pattern )* = #( #value )*; });
+			}
 		}
 	}
 }
 
 impl ControlTag {
+	/// A plain `for` loop is emitted unless loop metadata or an `{:empty}`
+	/// branch is requested. Loop metadata only peeks one item ahead to know
+	/// whether the current item is the last one, so the iterator is never
+	/// collected.
 	fn to_tokens_for_loop(
 		left_side: &Vec<TokenTree>,
 		right_side: &Vec<TokenTree>,
+		loop_binding: &Option<Ident>,
 		contents: &[Content],
+		empty_contents: &Option<Vec<Content>>,
 		tokens: &mut TokenStream,
 	) {
 		let body = to_push_stmts(contents);
 
-		let output = quote! {
-			for ( #( #left_side )* ) in ( #( #right_side )* ).into_iter() {
-				#body
+		let output = match (loop_binding, empty_contents) {
+			(None, None) => quote! {
+				for ( #( #left_side )* ) in ( #( #right_side )* ).into_iter() {
+					#body
+				}
+			},
+			(None, Some(empty_contents)) => {
+				let empty_body = to_push_stmts(empty_contents);
+				quote! {
+					{
+						let mut __tidos_is_empty = true;
+						for ( #( #left_side )* ) in ( #( #right_side )* ).into_iter() {
+							__tidos_is_empty = false;
+							#body
+						}
+						if __tidos_is_empty { #empty_body }
+					}
+				}
+			}
+			(Some(loop_binding), empty_contents) => {
+				let empty_branch = empty_contents.as_ref().map(|empty_contents| {
+					let empty_body = to_push_stmts(empty_contents);
+					quote! { if __tidos_index == 0 { #empty_body } }
+				});
+				quote! {
+					{
+						let mut __tidos_iter = ::std::iter::Iterator::peekable(
+							::std::iter::IntoIterator::into_iter( #( #right_side )* )
+						);
+						let mut __tidos_index: usize = 0;
+						while let Some( #( #left_side )* ) = __tidos_iter.next() {
+							let #loop_binding = tidos::LoopInfo {
+								index: __tidos_index,
+								first: __tidos_index == 0,
+								last: __tidos_iter.peek().is_none(),
+							};
+							__tidos_index += 1;
+							#body
+						}
+						#empty_branch
+					}
+				}
 			}
 		};
 
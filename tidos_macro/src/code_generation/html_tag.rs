@@ -1,7 +1,7 @@
 use crate::code_generation::component::{flush_flat, process_native_tag, to_push_stmts};
 use crate::tokens::{Attribute, Content, ControlTag, HTMLTag};
-use proc_macro2::{Ident, TokenStream};
-use quote::{quote, ToTokens, TokenStreamExt};
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote, quote_spanned, ToTokens, TokenStreamExt};
 
 impl ToTokens for HTMLTag {
 	fn to_tokens(&self, tokens: &mut TokenStream) {
@@ -20,13 +20,13 @@ impl ToTokens for HTMLTag {
 }
 
 fn custom_element_to_tokens(html_tag: &HTMLTag) -> TokenStream {
-	let tag = html_tag.tag.as_str();
+	let has_default_flag = html_tag.attributes.has_default_flag;
 
-	let mut attributes = html_tag
+	let mut props = html_tag
 		.attributes
 		.attributes
 		.iter()
-		.map(Attribute::to_tokens_custom_element)
+		.map(Attribute::to_prop_tokens)
 		.collect::<Vec<_>>();
 
 	if matches!(
@@ -34,33 +34,79 @@ fn custom_element_to_tokens(html_tag: &HTMLTag) -> TokenStream {
 		Some(Content::ControlTag(ControlTag::Slot { .. }))
 	) {
 		for child in &html_tag.children {
-			if let Content::ControlTag(ControlTag::Slot { name, contents }) = child {
+			if let Content::ControlTag(ControlTag::Slot {
+				name,
+				params,
+				contents,
+			}) = child
+			{
 				let body = to_push_stmts(contents);
-				attributes
-					.push(quote! { #name: Box::new(move |page: &mut tidos::Page| { #body }) });
+				if let Some(params) = params {
+					props.push((
+						name.clone(),
+						quote! { tidos::internals::slot_with(move |page: &mut tidos::Page, #params| { #body }) },
+					));
+				} else {
+					props.push((
+						name.clone(),
+						quote! { tidos::internals::slot(move |page: &mut tidos::Page| { #body }) },
+					));
+				}
 			}
 		}
 	} else if !html_tag.children.is_empty() {
 		let body = to_push_stmts(&html_tag.children);
-		attributes.push(quote! {
-			0: Box::new(move |page: &mut tidos::Page| { #body })
-		});
+		// The children fill the first field of a tuple struct, which the props
+		// builder exposes as `_0`.
+		props.push((
+			format_ident!("_0"),
+			quote! { tidos::internals::slot(move |page: &mut tidos::Page| { #body }) },
+		));
 	}
 
-	let component_name = Ident::new(tag, html_tag.tag_span).to_token_stream();
+	let component_name = &html_tag.component_path;
 
-	let inner = if html_tag.attributes.has_default_flag && attributes.is_empty() {
-		quote! { #component_name { ..Default::default() }.to_render(page); }
-	} else if html_tag.attributes.has_default_flag && !attributes.is_empty() {
-		quote! { #component_name { #( #attributes ),*, ..Default::default() }.to_render(page); }
+	// `..` keeps the struct update syntax so any `Default` component works,
+	// everything else goes through the builder generated by `#[derive(Props)]`.
+	let construction = if has_default_flag {
+		let fields = props.iter().map(|(name, value)| {
+			if name == "_0" {
+				quote! { 0: #value }
+			} else {
+				quote! { #name: #value }
+			}
+		});
+		quote! { #component_name { #( #fields, )* ..Default::default() } }
 	} else {
-		quote! { #component_name { #( #attributes ),* }.to_render(page); }
+		// `__prop_<name>` converts the value with `tidos::IntoProp` where the
+		// prop's type allows it, see `#[derive(Props)]`.
+		let setters = props.iter().map(|(name, value)| {
+			let setter = format_ident!("__prop_{}", name, span = name.span());
+			quote_spanned! { html_tag.tag_span=> .#setter(#value) }
+		});
+		// `Name::builder()` rather than `<Name as Props>::builder()`, so the
+		// component's generics are inferred instead of having to be spelled out.
+		// The tag span makes a missing required prop point at the tag.
+		quote_spanned! { html_tag.tag_span=>
+			{
+				use tidos::Props as _;
+				#component_name::builder() #( #setters )* .build()
+			}
+		}
 	};
 
-	if let Some(closing_span) = html_tag.closing_tag_span {
-		let closing_ident = Ident::new(tag, closing_span);
-		quote! { { let _: #closing_ident; #inner } }
+	// The closing tag renders the component, which ties its span to the
+	// component type. In expression position the component's generics are
+	// inferred, and enums work as well as structs.
+	if let Some(closing_path) = &html_tag.closing_component_path {
+		quote! {
+			{
+				use tidos::Component as _;
+				let component = #construction;
+				#closing_path::to_render(&component, page);
+			}
+		}
 	} else {
-		inner
+		quote! { #construction.to_render(page); }
 	}
 }
@@ -0,0 +1,49 @@
+use crate::css::Targets;
+use proc_macro2::TokenStream;
+use quote::quote;
+use std::path::PathBuf;
+
+/// The macros' settings from the `[css]` table in the calling crate's
+/// `Tidos.toml`, next to its `Cargo.toml`. The file is optional, as is the
+/// table.
+#[derive(Debug, Default)]
+pub struct Config {
+	pub targets: Targets,
+	/// The `Tidos.toml` the settings were read from.
+	path: Option<PathBuf>,
+}
+
+impl Config {
+	pub fn read() -> Result<Config, String> {
+		let Some(manifest_dir) = std::env::var_os("CARGO_MANIFEST_DIR") else {
+			return Ok(Config::default());
+		};
+		let path = PathBuf::from(manifest_dir).join("Tidos.toml");
+		let Ok(text) = std::fs::read_to_string(&path) else {
+			return Ok(Config::default());
+		};
+		let table = text
+			.parse::<toml::Table>()
+			.map_err(|error| format!("couldn't parse `{}`: {error}", path.display()))?;
+
+		let targets = match table.get("css").and_then(|css| css.get("targets")) {
+			Some(toml::Value::Table(targets)) => Targets::from_table(targets)?,
+			Some(_) => {
+				return Err("expected `[css.targets]` in `Tidos.toml` to be a table".to_string())
+			}
+			None => Targets::default(),
+		};
+
+		Ok(Config {
+			targets,
+			path: Some(path),
+		})
+	}
+
+	/// An item that makes the compiler track `Tidos.toml`, so changing the
+	/// targets rebuilds the stylesheets.
+	pub fn track(&self) -> Option<TokenStream> {
+		let path = self.path.as_ref()?.to_string_lossy();
+		Some(quote! { const _: &[u8] = include_bytes!(#path); })
+	}
+}
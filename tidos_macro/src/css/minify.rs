@@ -0,0 +1,98 @@
+use cssparser::{ParseError, Parser, Token};
+
+/// Writes `css` out again without comments and with as little whitespace as
+/// possible, e.g. `0 auto , 0.5rem` becomes `0 auto,.5rem`.
+///
+/// `value` is set for declaration values, where colours such as `#ffcc00`
+/// are shortened to `#fc0`. In selectors that would rename an id.
+pub fn minify(css: &str, value: bool) -> String {
+	let mut output = String::new();
+	write_tokens(&mut Parser::new(css), &mut output, value);
+	output
+}
+
+fn write_tokens(parser: &mut Parser, output: &mut String, value: bool) {
+	let mut whitespace = false;
+	let mut previous: Option<Token> = None;
+	loop {
+		let start = parser.position();
+		let token = match parser.next_including_whitespace_and_comments() {
+			Ok(token) => token.clone(),
+			Err(_) => return,
+		};
+		if matches!(token, Token::WhiteSpace(_) | Token::Comment(_)) {
+			whitespace = true;
+			continue;
+		}
+		if whitespace
+			&& previous
+				.as_ref()
+				.is_some_and(|previous| !ends_separator(previous))
+			&& !starts_separator(&token)
+		{
+			output.push(' ');
+		}
+		whitespace = false;
+
+		let text = parser.slice_from(start);
+		match &token {
+			Token::Function(_)
+			| Token::ParenthesisBlock
+			| Token::SquareBracketBlock
+			| Token::CurlyBracketBlock => {
+				output.push_str(text);
+				let _ = parser.parse_nested_block(|nested| {
+					write_tokens(nested, output, value);
+					Ok::<_, ParseError<()>>(())
+				});
+				output.push(match token {
+					Token::SquareBracketBlock => ']',
+					Token::CurlyBracketBlock => '}',
+					_ => ')',
+				});
+			}
+			Token::Number { .. } | Token::Percentage { .. } | Token::Dimension { .. } => {
+				output.push_str(&without_leading_zero(text));
+			}
+			Token::Hash(hash) | Token::IDHash(hash) if value => {
+				output.push('#');
+				output.push_str(&short_colour(hash));
+			}
+			_ => output.push_str(text),
+		}
+		previous = Some(token);
+	}
+}
+
+/// Whitespace after these is never needed.
+fn ends_separator(token: &Token) -> bool {
+	matches!(token, Token::Comma | Token::Colon | Token::Delim('/'))
+}
+
+/// Whitespace before these is never needed. A colon isn't one of them, in a
+/// selector `a :hover` differs from `a:hover`.
+fn starts_separator(token: &Token) -> bool {
+	matches!(token, Token::Comma | Token::Delim('!' | '/'))
+}
+
+/// `0.5` as `.5`.
+fn without_leading_zero(number: &str) -> String {
+	match number.split_at(number.find("0.").unwrap_or(number.len())) {
+		(sign @ ("" | "-" | "+"), rest) if !rest.is_empty() => format!("{sign}{}", &rest[1..]),
+		_ => number.to_string(),
+	}
+}
+
+/// `ffcc00` as `fc0`, and `ffcc0088` as `fc08`, when every pair repeats.
+fn short_colour(hash: &str) -> String {
+	let bytes = hash.as_bytes();
+	let is_hex = bytes.iter().all(u8::is_ascii_hexdigit);
+	if is_hex && matches!(bytes.len(), 6 | 8) && bytes.chunks(2).all(|pair| pair[0] == pair[1]) {
+		bytes
+			.chunks(2)
+			.map(|pair| char::from(pair[0]).to_ascii_lowercase())
+			.collect()
+	} else {
+		hash.to_string()
+	}
+}
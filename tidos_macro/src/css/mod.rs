@@ -0,0 +1,399 @@
+use cssparser::{ParseError, Parser, SourcePosition, Token};
+use minify::minify;
+use std::collections::HashSet;
+
+mod minify;
+mod prefix;
+mod validate;
+
+pub use prefix::Targets;
+pub use validate::validate;
+
+/// Rewrites a stylesheet so it only applies to the elements of one view,
+/// the way Svelte scopes a component's `<style>`.
+///
+/// Every compound selector requires the `data-<id>` attribute that the view
+/// stamps on its native elements, so rules neither leak into child
+/// components nor into the rest of the page. `:global(…)` opts a selector
+/// out, and a top-level `&` refers to the element with the `<id>` class, the
+/// one `scoped_css!` is assigned to. Keyframes are renamed to `<id>-<name>`,
+/// along with the `animation` declarations that use them.
+///
+/// The output is minified, and declarations get the vendor-prefixed copies
+/// `targets` need.
+pub fn scope_stylesheet(css: &str, id: &str, targets: &Targets) -> String {
+	let mut keyframes = HashSet::new();
+	collect_keyframes(&mut Parser::new(css), &mut keyframes);
+
+	let scope = Scope {
+		id,
+		keyframes,
+		targets,
+	};
+	let mut output = String::new();
+	scope.block(&mut Parser::new(css), &mut output, Context::TopLevel);
+	output
+}
+
+struct Scope<'a> {
+	id: &'a str,
+	keyframes: HashSet<String>,
+	targets: &'a Targets,
+}
+
+/// Where a block is, which decides how its selectors are written.
+#[derive(Clone, Copy, PartialEq)]
+enum Context {
+	/// The stylesheet itself, where `&` is the element with the scope's class.
+	TopLevel,
+	/// Inside a style rule, where `&` is left to the browser.
+	Rule,
+	/// Inside `@keyframes`, `@font-face` and the like, whose selectors and
+	/// descriptors aren't about elements.
+	Plain,
+}
+
+/// One compound selector, e.g. `a.active:hover::after`.
+#[derive(Default)]
+struct Compound {
+	text: String,
+	/// Contains `&`, which is already scoped by its parent rule.
+	nesting: bool,
+	/// Written as `:global(…)`.
+	global: bool,
+	/// Where the pseudo-element starts, the marker has to go before it.
+	pseudo_element: Option<usize>,
+}
+
+impl Scope<'_> {
+	/// The rules and declarations in a stylesheet or block.
+	fn block(&self, parser: &mut Parser, output: &mut String, context: Context) {
+		loop {
+			let state = parser.state();
+			let token = match parser.next_including_whitespace_and_comments() {
+				Ok(token) => token.clone(),
+				Err(_) => return,
+			};
+			match token {
+				Token::WhiteSpace(_) | Token::Comment(_) | Token::Semicolon => {}
+				Token::AtKeyword(name) => self.at_rule(&name, parser, output, context),
+				_ => {
+					parser.reset(&state);
+					self.rule_or_declaration(parser, output, context);
+				}
+			}
+		}
+	}
+
+	fn rule_or_declaration(&self, parser: &mut Parser, output: &mut String, context: Context) {
+		let start = parser.position();
+		let (end, has_block) = prelude_end(parser);
+		let prelude = parser.slice(start..end).trim();
+
+		if !has_block {
+			output.push_str(&self.declaration(prelude));
+			output.push(';');
+		} else if context == Context::Plain {
+			output.push_str(&minify(prelude, false));
+			self.nested_block(parser, output, Context::Plain);
+		} else {
+			output.push_str(&self.selector_list(prelude, context));
+			self.nested_block(parser, output, Context::Rule);
+		}
+	}
+
+	fn at_rule(&self, name: &str, parser: &mut Parser, output: &mut String, context: Context) {
+		let start = parser.position();
+		let (end, has_block) = prelude_end(parser);
+		let prelude = minify(parser.slice(start..end), false);
+
+		output.push('@');
+		output.push_str(name);
+		if is_keyframes(name) {
+			output.push(' ');
+			output.push_str(&self.keyframes_name(&prelude));
+		} else if !prelude.is_empty() {
+			output.push(' ');
+			output.push_str(&prelude);
+		}
+
+		if !has_block {
+			output.push(';');
+			return;
+		}
+		match name.to_ascii_lowercase().as_str() {
+			"media" | "supports" | "container" | "layer" | "scope" | "starting-style"
+			| "document" | "-moz-document" => self.nested_block(parser, output, context),
+			_ => self.nested_block(parser, output, Context::Plain),
+		}
+	}
+
+	/// The `{ … }` block the parser just returned.
+	fn nested_block(&self, parser: &mut Parser, output: &mut String, context: Context) {
+		output.push('{');
+		let _ = parser.parse_nested_block(|nested| {
+			self.block(nested, output, context);
+			Ok::<_, ParseError<()>>(())
+		});
+		// The last declaration doesn't need its `;`.
+		if output.ends_with(';') {
+			output.pop();
+		}
+		output.push('}');
+	}
+
+	fn selector_list(&self, selectors: &str, context: Context) -> String {
+		let mut parser = Parser::new(selectors);
+		let mut output = String::new();
+		let mut compound = Compound::default();
+		let mut whitespace = false;
+
+		loop {
+			let start = parser.position();
+			let token = match parser.next_including_whitespace_and_comments() {
+				Ok(token) => token.clone(),
+				Err(_) => break,
+			};
+			match token {
+				Token::WhiteSpace(_) | Token::Comment(_) => whitespace = true,
+				Token::Comma | Token::Delim('>' | '+' | '~') => {
+					self.flush(&mut compound, &mut output);
+					output.push_str(parser.slice_from(start));
+					whitespace = false;
+				}
+				token => {
+					// Whitespace between two compounds is the descendant combinator.
+					if whitespace && !compound.text.is_empty() {
+						self.flush(&mut compound, &mut output);
+						output.push(' ');
+					}
+					whitespace = false;
+
+					match token {
+						Token::Delim('&') => {
+							compound.nesting = true;
+							if context == Context::Rule {
+								compound.text.push('&');
+							} else {
+								compound.text.push('.');
+								compound.text.push_str(self.id);
+							}
+						}
+						Token::Colon => self.pseudo(&mut parser, &mut compound),
+						token => {
+							if opens_block(&token) {
+								skip_block(&mut parser);
+							}
+							compound
+								.text
+								.push_str(&minify(parser.slice_from(start), false));
+						}
+					}
+				}
+			}
+		}
+		self.flush(&mut compound, &mut output);
+
+		output
+	}
+
+	/// A pseudo-class or pseudo-element, after its first `:`.
+	fn pseudo(&self, parser: &mut Parser, compound: &mut Compound) {
+		let start = parser.position();
+		let token = match parser.next_including_whitespace_and_comments() {
+			Ok(token) => token.clone(),
+			Err(_) => {
+				compound.text.push(':');
+				return;
+			}
+		};
+		match token {
+			Token::Function(name) if name.eq_ignore_ascii_case("global") => {
+				let selector = parser
+					.parse_nested_block(|nested| {
+						let start = nested.position();
+						while nested.next_including_whitespace_and_comments().is_ok() {}
+						Ok::<_, ParseError<()>>(nested.slice_from(start).to_string())
+					})
+					.unwrap_or_default();
+				compound.global = true;
+				compound.text.push_str(selector.trim());
+			}
+			token => {
+				let is_pseudo_element = match &token {
+					Token::Colon => true,
+					// Legacy pseudo-elements written with a single colon.
+					Token::Ident(name) => ["before", "after", "first-line", "first-letter"]
+						.iter()
+						.any(|legacy| name.eq_ignore_ascii_case(legacy)),
+					_ => false,
+				};
+				if is_pseudo_element {
+					compound.pseudo_element.get_or_insert(compound.text.len());
+				}
+				if opens_block(&token) {
+					skip_block(parser);
+				}
+				compound.text.push(':');
+				compound
+					.text
+					.push_str(&minify(parser.slice_from(start), false));
+				if matches!(token, Token::Colon) {
+					self.pseudo_element_name(parser, compound);
+				}
+			}
+		}
+	}
+
+	/// The name after `::`, e.g. `before` or `part(label)`.
+	fn pseudo_element_name(&self, parser: &mut Parser, compound: &mut Compound) {
+		let start = parser.position();
+		if let Ok(token) = parser.next_including_whitespace_and_comments() {
+			if opens_block(&token.clone()) {
+				skip_block(parser);
+			}
+			compound.text.push_str(parser.slice_from(start));
+		}
+	}
+
+	/// Add the scope's attribute to a compound selector and write it out.
+	fn flush(&self, compound: &mut Compound, output: &mut String) {
+		let Compound {
+			mut text,
+			nesting,
+			global,
+			pseudo_element,
+		} = std::mem::take(compound);
+		if text.is_empty() {
+			return;
+		}
+		if !nesting && !global {
+			let marker = format!("[data-{}]", self.id);
+			text.insert_str(pseudo_element.unwrap_or(text.len()), &marker);
+		}
+		output.push_str(&text);
+	}
+
+	fn keyframes_name(&self, name: &str) -> String {
+		match name
+			.strip_prefix('"')
+			.and_then(|name| name.strip_suffix('"'))
+		{
+			Some(quoted) => format!("\"{}-{quoted}\"", self.id),
+			None => format!("{}-{name}", self.id),
+		}
+	}
+
+	/// A minified declaration, preceded by the prefixed copies the targets
+	/// need.
+	fn declaration(&self, declaration: &str) -> String {
+		let Some((property, value)) = declaration.split_once(':') else {
+			return minify(declaration, true);
+		};
+		let property = property.trim();
+		let value = self.rename_keyframes(property, &minify(value, true));
+
+		let mut output = String::new();
+		for prefixed in self.targets.prefixed(property, &value) {
+			output.push_str(&prefixed);
+			output.push(';');
+		}
+		output.push_str(property);
+		output.push(':');
+		output.push_str(&value);
+		output
+	}
+
+	/// Renames the keyframes an `animation` or `animation-name` declaration
+	/// refers to.
+	fn rename_keyframes(&self, property: &str, value: &str) -> String {
+		let name = property.to_ascii_lowercase();
+		let name = name
+			.strip_prefix("-webkit-")
+			.or_else(|| name.strip_prefix("-moz-"))
+			.unwrap_or(&name);
+		if self.keyframes.is_empty() || !matches!(name, "animation" | "animation-name") {
+			return value.to_string();
+		}
+
+		let mut output = String::new();
+		let mut word = String::new();
+		for char in value.chars().chain(std::iter::once(' ')) {
+			if char.is_alphanumeric() || char == '-' || char == '_' {
+				word.push(char);
+				continue;
+			}
+			if self.keyframes.contains(&word) {
+				output.push_str(self.id);
+				output.push('-');
+			}
+			output.push_str(&word);
+			word.clear();
+			output.push(char);
+		}
+		output.pop();
+
+		output
+	}
+}
+
+fn collect_keyframes(parser: &mut Parser, names: &mut HashSet<String>) {
+	while let Ok(token) = parser.next_including_whitespace_and_comments() {
+		match token.clone() {
+			Token::AtKeyword(name) if is_keyframes(&name) => {
+				if let Ok(Token::Ident(name) | Token::QuotedString(name)) = parser.next() {
+					names.insert(name.to_string());
+				}
+			}
+			token if opens_block(&token) => {
+				let _ = parser.parse_nested_block(|nested| {
+					collect_keyframes(nested, names);
+					Ok::<_, ParseError<()>>(())
+				});
+			}
+			_ => {}
+		}
+	}
+}
+
+fn is_keyframes(name: &str) -> bool {
+	let name = name.to_ascii_lowercase();
+	matches!(
+		name.as_str(),
+		"keyframes" | "-webkit-keyframes" | "-moz-keyframes" | "-o-keyframes"
+	)
+}
+
+/// Consumes tokens up to a `;` or a `{ … }` block, returns where the prelude
+/// ends and whether it was followed by a block.
+fn prelude_end(parser: &mut Parser) -> (SourcePosition, bool) {
+	loop {
+		let position = parser.position();
+		match parser.next_including_whitespace_and_comments() {
+			Err(_) | Ok(Token::Semicolon) => return (position, false),
+			Ok(Token::CurlyBracketBlock) => return (position, true),
+			Ok(token) => {
+				if opens_block(&token.clone()) {
+					skip_block(parser);
+				}
+			}
+		}
+	}
+}
+
+pub(crate) fn opens_block(token: &Token) -> bool {
+	matches!(
+		token,
+		Token::Function(_)
+			| Token::ParenthesisBlock
+			| Token::SquareBracketBlock
+			| Token::CurlyBracketBlock
+	)
+}
+
+fn skip_block(parser: &mut Parser) {
+	let _ = parser.parse_nested_block(|nested| {
+		while nested.next_including_whitespace_and_comments().is_ok() {}
+		Ok::<_, ParseError<()>>(())
+	});
+}
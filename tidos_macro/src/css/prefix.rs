@@ -0,0 +1,119 @@
+/// The oldest browser versions a stylesheet has to work in, from the
+/// `[css.targets]` table in `Tidos.toml`:
+///
+/// ```toml
+/// [css.targets]
+/// safari = 13
+/// firefox = "78"
+/// ```
+///
+/// Without targets no vendor prefixes are added.
+#[derive(Debug, Default)]
+pub struct Targets {
+	browsers: Vec<(Browser, f32)>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Browser {
+	Chrome,
+	Firefox,
+	Safari,
+}
+
+/// Properties that browsers supported with a prefix first, with the version
+/// that supports them without one. `f32::INFINITY` means none does yet.
+const PREFIXES: &[(&str, &str, Browser, f32)] = &[
+	("appearance", "-webkit-", Browser::Chrome, 84.0),
+	("appearance", "-webkit-", Browser::Safari, 15.4),
+	("appearance", "-moz-", Browser::Firefox, 80.0),
+	("backdrop-filter", "-webkit-", Browser::Safari, 18.0),
+	("backface-visibility", "-webkit-", Browser::Safari, 15.4),
+	("background-clip", "-webkit-", Browser::Chrome, 120.0),
+	("background-clip", "-webkit-", Browser::Safari, 14.0),
+	("box-decoration-break", "-webkit-", Browser::Chrome, 130.0),
+	(
+		"box-decoration-break",
+		"-webkit-",
+		Browser::Safari,
+		f32::INFINITY,
+	),
+	("hyphens", "-webkit-", Browser::Safari, 17.0),
+	("mask", "-webkit-", Browser::Chrome, 120.0),
+	("mask", "-webkit-", Browser::Safari, 15.4),
+	("mask-image", "-webkit-", Browser::Chrome, 120.0),
+	("mask-image", "-webkit-", Browser::Safari, 15.4),
+	("mask-position", "-webkit-", Browser::Chrome, 120.0),
+	("mask-position", "-webkit-", Browser::Safari, 15.4),
+	("mask-repeat", "-webkit-", Browser::Chrome, 120.0),
+	("mask-repeat", "-webkit-", Browser::Safari, 15.4),
+	("mask-size", "-webkit-", Browser::Chrome, 120.0),
+	("mask-size", "-webkit-", Browser::Safari, 15.4),
+	("tab-size", "-moz-", Browser::Firefox, 91.0),
+	(
+		"text-size-adjust",
+		"-webkit-",
+		Browser::Safari,
+		f32::INFINITY,
+	),
+	("user-select", "-webkit-", Browser::Safari, f32::INFINITY),
+	("user-select", "-moz-", Browser::Firefox, 69.0),
+];
+
+impl Targets {
+	/// Reads the `[css.targets]` table, e.g. `{ safari = 13, chrome = "90" }`.
+	/// `edge` counts as Chrome and `ios_safari` as Safari.
+	pub fn from_table(table: &toml::Table) -> Result<Targets, String> {
+		let mut browsers = Vec::new();
+		for (name, version) in table {
+			let browser = match name.as_str() {
+				"chrome" | "edge" => Browser::Chrome,
+				"firefox" => Browser::Firefox,
+				"safari" | "ios_safari" => Browser::Safari,
+				_ => {
+					return Err(format!(
+						"unknown browser `{name}` in `[css.targets]`, expected `chrome`, `edge`, `firefox`, `safari` or `ios_safari`"
+					));
+				}
+			};
+			let version = match version {
+				toml::Value::Integer(version) => Some(*version as f32),
+				toml::Value::Float(version) => Some(*version as f32),
+				toml::Value::String(version) => version.parse().ok(),
+				_ => None,
+			}
+			.ok_or_else(|| {
+				format!("expected a version for `{name}` in `[css.targets]`, e.g. `{name} = 15.4`")
+			})?;
+			browsers.push((browser, version));
+		}
+		Ok(Targets { browsers })
+	}
+
+	/// The prefixed copies of `property: value` the targets need, to be
+	/// written before it.
+	pub fn prefixed(&self, property: &str, value: &str) -> Vec<String> {
+		let property = property.to_ascii_lowercase();
+		let mut declarations: Vec<String> = Vec::new();
+		for (name, prefix, browser, unprefixed_in) in PREFIXES {
+			if *name == property && self.needs(*browser, *unprefixed_in) {
+				let declaration = format!("{prefix}{property}:{value}");
+				if !declarations.contains(&declaration) {
+					declarations.push(declaration);
+				}
+			}
+		}
+		if property == "position"
+			&& value.eq_ignore_ascii_case("sticky")
+			&& self.needs(Browser::Safari, 13.0)
+		{
+			declarations.push("position:-webkit-sticky".to_string());
+		}
+		declarations
+	}
+
+	fn needs(&self, browser: Browser, unprefixed_in: f32) -> bool {
+		self.browsers
+			.iter()
+			.any(|(target, version)| *target == browser && *version < unprefixed_in)
+	}
+}
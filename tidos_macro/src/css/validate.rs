@@ -0,0 +1,268 @@
+use crate::css::opens_block;
+use cssparser::{ParseError, Parser, SourcePosition, Token};
+
+/// A syntax error in a stylesheet, at a byte offset into it.
+#[derive(Debug)]
+pub struct SyntaxError {
+	pub message: String,
+	pub offset: usize,
+}
+
+impl SyntaxError {
+	fn new(message: impl Into<String>, position: SourcePosition) -> SyntaxError {
+		SyntaxError {
+			message: message.into(),
+			offset: position.byte_index(),
+		}
+	}
+
+	/// The 1-based line and 0-based column of the error in `css`.
+	pub fn line_column(&self, css: &str) -> (usize, usize) {
+		let before = &css[..self.offset];
+		let line = before.matches('\n').count() + 1;
+		let column = before.len() - before.rfind('\n').map_or(0, |index| index + 1);
+		(line, column)
+	}
+}
+
+/// Checks that `css` is well-formed: blocks and strings are closed, rules
+/// have a selector, and their bodies consist of `property: value`
+/// declarations and nested rules.
+pub fn validate(css: &str) -> Result<(), SyntaxError> {
+	check_block(&mut Parser::new(css), false)
+}
+
+fn check_block(parser: &mut Parser, in_rule: bool) -> Result<(), SyntaxError> {
+	loop {
+		let start = parser.position();
+		let state = parser.state();
+		let token = match parser.next_including_whitespace_and_comments() {
+			Ok(token) => token.clone(),
+			Err(_) => return Ok(()),
+		};
+		let at_rule = match token {
+			Token::WhiteSpace(_) | Token::Comment(_) | Token::Semicolon => continue,
+			Token::AtKeyword(name) => Some(name.to_ascii_lowercase()),
+			_ => {
+				parser.reset(&state);
+				None
+			}
+		};
+
+		let prelude_start = parser.position();
+		let has_block = check_prelude(parser)?;
+		let prelude = parser.slice_from(prelude_start);
+		let prelude = prelude.trim_end_matches(['{', ';']).trim();
+
+		match (&at_rule, has_block) {
+			(Some(name), true) => {
+				let conditional = matches!(
+					name.as_str(),
+					"media" | "supports" | "container" | "layer" | "scope" | "starting-style"
+				);
+				check_nested_block(parser, start, in_rule || !conditional)?;
+			}
+			(Some(_), false) => {}
+			(None, true) if prelude.is_empty() => {
+				return Err(SyntaxError::new("expected a selector before `{`", start));
+			}
+			(None, true) => {
+				let selector = parser.slice_from(prelude_start);
+				let offset =
+					prelude_start.byte_index() + selector.len() - selector.trim_start().len();
+				check_selector(prelude, offset)?;
+				check_nested_block(parser, start, true)?;
+			}
+			(None, false) if !in_rule => {
+				return Err(SyntaxError::new(
+					format!("expected a rule, e.g. `.title {{ {prelude} }}`"),
+					start,
+				));
+			}
+			(None, false) => match prelude.split_once(':') {
+				None => {
+					return Err(SyntaxError::new(
+						format!("expected a declaration, e.g. `color: red`, found `{prelude}`"),
+						start,
+					));
+				}
+				Some((property, value)) if value.trim().is_empty() => {
+					return Err(SyntaxError::new(
+						format!("expected a value for `{}`", property.trim()),
+						start,
+					));
+				}
+				Some(_) => {}
+			},
+		}
+	}
+}
+
+/// Checks that a style rule's selector list has no empty selectors, dangling
+/// combinators, or `.` and `:` without a name. `offset` is where `selector`
+/// starts in the stylesheet.
+fn check_selector(selector: &str, offset: usize) -> Result<(), SyntaxError> {
+	let error = |message: String, position: SourcePosition| SyntaxError {
+		message,
+		offset: offset + position.byte_index(),
+	};
+	let mut parser = Parser::new(selector);
+	// The `,` or combinator that still needs a selector after it.
+	let mut dangling: Option<(char, SourcePosition)> = None;
+	let mut empty = true;
+	loop {
+		let position = parser.position();
+		let token = match parser.next_including_whitespace_and_comments() {
+			Ok(token) => token.clone(),
+			Err(_) => break,
+		};
+		match token {
+			Token::WhiteSpace(_) | Token::Comment(_) => {}
+			Token::Comma if empty => {
+				return Err(error(
+					"expected a selector before `,`".to_string(),
+					position,
+				));
+			}
+			Token::Comma => {
+				dangling = Some((',', position));
+				empty = true;
+			}
+			Token::Delim(combinator @ ('>' | '+' | '~')) => {
+				if let Some((previous, _)) = dangling.filter(|(previous, _)| *previous != ',') {
+					return Err(error(
+						format!("expected a selector between `{previous}` and `{combinator}`"),
+						position,
+					));
+				}
+				dangling = Some((combinator, position));
+			}
+			Token::Delim('.') => {
+				let name = parser.next_including_whitespace_and_comments().cloned();
+				if !matches!(name, Ok(Token::Ident(_))) {
+					return Err(error(
+						"expected a class name after `.`".to_string(),
+						position,
+					));
+				}
+				dangling = None;
+				empty = false;
+			}
+			Token::Colon => {
+				let mut name = parser.next_including_whitespace_and_comments().cloned();
+				if matches!(name, Ok(Token::Colon)) {
+					name = parser.next_including_whitespace_and_comments().cloned();
+				}
+				if !matches!(name, Ok(Token::Ident(_) | Token::Function(_))) {
+					return Err(error(
+						"expected a pseudo-class after `:`".to_string(),
+						position,
+					));
+				}
+				dangling = None;
+				empty = false;
+			}
+			_ => {
+				dangling = None;
+				empty = false;
+			}
+		}
+	}
+	match dangling {
+		Some((previous, position)) => Err(error(
+			format!("expected a selector after `{previous}`"),
+			position,
+		)),
+		None => Ok(()),
+	}
+}
+
+/// Checks the tokens up to a `;` or `{`, returns whether a block follows.
+fn check_prelude(parser: &mut Parser) -> Result<bool, SyntaxError> {
+	loop {
+		let position = parser.position();
+		let token = match parser.next_including_whitespace_and_comments() {
+			Ok(token) => token.clone(),
+			Err(_) => return Ok(false),
+		};
+		match token {
+			Token::Semicolon => return Ok(false),
+			Token::CurlyBracketBlock => return Ok(true),
+			token => {
+				check_token(&token, position)?;
+				if opens_block(&token) {
+					check_nested_tokens(parser, position, &token)?;
+				}
+			}
+		}
+	}
+}
+
+fn check_token(token: &Token, position: SourcePosition) -> Result<(), SyntaxError> {
+	let message = match token {
+		Token::BadString(_) => "unterminated string",
+		Token::BadUrl(_) => "invalid `url(…)`, quote the URL",
+		Token::CloseCurlyBracket => "unexpected `}`",
+		Token::CloseParenthesis => "unexpected `)`",
+		Token::CloseSquareBracket => "unexpected `]`",
+		_ => return Ok(()),
+	};
+	Err(SyntaxError::new(message, position))
+}
+
+/// The `{ … }` block the parser just returned, opened at `start`.
+fn check_nested_block(
+	parser: &mut Parser,
+	start: SourcePosition,
+	in_rule: bool,
+) -> Result<(), SyntaxError> {
+	let mut result = Ok(());
+	let mut end = parser.position();
+	let _ = parser.parse_nested_block(|nested| {
+		result = check_block(nested, in_rule);
+		end = nested.position();
+		Ok::<_, ParseError<()>>(())
+	});
+	result?;
+	// The closing `}` is consumed after the block's contents, unless the
+	// stylesheet ended first.
+	if parser.position() == end {
+		return Err(SyntaxError::new("unclosed `{`", start));
+	}
+	Ok(())
+}
+
+/// The contents of a function, `( … )` or `[ … ]` the parser just returned.
+fn check_nested_tokens(
+	parser: &mut Parser,
+	start: SourcePosition,
+	token: &Token,
+) -> Result<(), SyntaxError> {
+	let mut result = Ok(());
+	let mut end = parser.position();
+	let _ = parser.parse_nested_block(|nested| {
+		result = (|| loop {
+			let position = nested.position();
+			let token = match nested.next_including_whitespace_and_comments() {
+				Ok(token) => token.clone(),
+				Err(_) => return Ok(()),
+			};
+			check_token(&token, position)?;
+			if opens_block(&token) {
+				check_nested_tokens(nested, position, &token)?;
+			}
+		})();
+		end = nested.position();
+		Ok::<_, ParseError<()>>(())
+	});
+	result?;
+	let open = match token {
+		Token::SquareBracketBlock => '[',
+		Token::CurlyBracketBlock => '{',
+		_ => '(',
+	};
+	if parser.position() == end {
+		return Err(SyntaxError::new(format!("unclosed `{open}`"), start));
+	}
+	Ok(())
+}
@@ -0,0 +1,234 @@
+use proc_macro2::{Span, TokenStream};
+use quote::{quote, ToTokens, TokenStreamExt};
+use syn::parse::{Parse, ParseStream};
+use syn::spanned::Spanned;
+use syn::visit_mut::VisitMut;
+use syn::{
+	parse_quote_spanned, Attribute, Block, FnArg, GenericParam, Generics, Ident, ItemFn, Lifetime,
+	LifetimeParam, Pat, ReturnType, Type, Visibility,
+};
+
+/// Name of the lifetime given to elided references in the parameter list.
+const PROPS_LIFETIME: &str = "'props";
+
+/// A function annotated with `#[component]`, turned into a props struct and a
+/// `Component` implementation.
+pub struct FunctionComponent {
+	attrs: Vec<Attribute>,
+	vis: Visibility,
+	name: Ident,
+	generics: Generics,
+	props: Vec<Prop>,
+	body: Box<Block>,
+}
+
+struct Prop {
+	name: Ident,
+	ty: Type,
+	/// `#[prop(…)]` attributes, forwarded to `#[derive(Props)]`.
+	attrs: Vec<Attribute>,
+}
+
+impl Parse for FunctionComponent {
+	fn parse(input: ParseStream) -> syn::Result<Self> {
+		let function = ItemFn::parse(input)?;
+		let ItemFn {
+			attrs,
+			vis,
+			sig,
+			block,
+			..
+		} = function;
+
+		if !sig
+			.ident
+			.to_string()
+			.starts_with(|c: char| c.is_ascii_uppercase())
+		{
+			return Err(syn::Error::new(
+				sig.ident.span(),
+				format!(
+					"#[component] functions must be named in PascalCase, because `view!` treats \
+					 lowercase tags as HTML elements. Rename `{}` to e.g. `{}`.",
+					sig.ident,
+					to_pascal_case(&sig.ident.to_string())
+				),
+			));
+		}
+		if let Some(asyncness) = sig.asyncness {
+			return Err(syn::Error::new_spanned(
+				asyncness,
+				"#[component] functions cannot be async",
+			));
+		}
+		if let ReturnType::Type(_, ty) = &sig.output {
+			return Err(syn::Error::new_spanned(
+				ty,
+				"#[component] functions render into the page and cannot return a value",
+			));
+		}
+
+		let mut generics = sig.generics;
+		let mut elided = ElidedLifetimes::default();
+		let mut props = Vec::with_capacity(sig.inputs.len());
+
+		for input in sig.inputs {
+			let typed = match input {
+				FnArg::Typed(typed) => typed,
+				FnArg::Receiver(receiver) => {
+					return Err(syn::Error::new_spanned(
+						receiver,
+						"#[component] functions cannot take `self`",
+					));
+				}
+			};
+
+			let name = match *typed.pat {
+				Pat::Ident(pat) if pat.by_ref.is_none() && pat.subpat.is_none() => pat.ident,
+				pat => {
+					return Err(syn::Error::new_spanned(
+						pat,
+						"#[component] parameters must be plain identifiers, e.g. `label: &str`",
+					));
+				}
+			};
+			if name == "page" {
+				return Err(syn::Error::new(
+					name.span(),
+					"`page` is reserved for the page being rendered, name the parameter differently",
+				));
+			}
+
+			let mut attrs = Vec::with_capacity(typed.attrs.len());
+			for attr in typed.attrs {
+				if attr.path().is_ident("default") {
+					attr.meta.require_path_only()?;
+					attrs.push(parse_quote_spanned! { attr.span()=> #[prop(optional)] });
+				} else if attr.path().is_ident("prop") {
+					attrs.push(attr);
+				} else {
+					return Err(syn::Error::new_spanned(
+						attr,
+						"unsupported attribute on a #[component] parameter, \
+						 must be: `#[default]` or `#[prop(…)]`",
+					));
+				}
+			}
+
+			let mut ty = *typed.ty;
+			if let Type::ImplTrait(impl_trait) = &ty {
+				return Err(syn::Error::new_spanned(
+					impl_trait,
+					"`impl Trait` is not supported in #[component] parameters, \
+					 declare a generic parameter on the function instead",
+				));
+			}
+			elided.visit_type_mut(&mut ty);
+
+			props.push(Prop { name, ty, attrs });
+		}
+
+		if elided.found {
+			generics.params.insert(
+				0,
+				GenericParam::Lifetime(LifetimeParam::new(props_lifetime())),
+			);
+		}
+
+		Ok(FunctionComponent {
+			attrs,
+			vis,
+			name: sig.ident,
+			generics,
+			props,
+			body: block,
+		})
+	}
+}
+
+impl ToTokens for FunctionComponent {
+	fn to_tokens(&self, tokens: &mut TokenStream) {
+		let FunctionComponent {
+			attrs,
+			vis,
+			name,
+			generics,
+			props,
+			body,
+		} = self;
+		let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+		let field_names = props.iter().map(|prop| &prop.name).collect::<Vec<_>>();
+		let field_attrs = props.iter().map(|prop| &prop.attrs);
+		let field_types = props.iter().map(|prop| &prop.ty);
+
+		tokens.append_all(quote! {
+			#( #attrs )*
+			#[derive(tidos::Props)]
+			#vis struct #name #generics #where_clause {
+				#(
+					#( #field_attrs )*
+					#vis #field_names: #field_types,
+				)*
+			}
+
+			impl #impl_generics tidos::Component for #name #ty_generics #where_clause {
+				fn to_render(&self, page: &mut tidos::Page) {
+					#[allow(unused_variables)]
+					let Self { #( #field_names ),* } = self;
+					#body
+				}
+			}
+		});
+	}
+}
+
+fn props_lifetime() -> Lifetime {
+	Lifetime::new(PROPS_LIFETIME, Span::call_site())
+}
+
+/// Names the elided lifetimes of `&T` and `'_`, which are allowed in a
+/// function signature but not in a struct field.
+#[derive(Default)]
+struct ElidedLifetimes {
+	found: bool,
+}
+
+impl VisitMut for ElidedLifetimes {
+	fn visit_type_reference_mut(&mut self, reference: &mut syn::TypeReference) {
+		if reference.lifetime.is_none() {
+			reference.lifetime = Some(props_lifetime());
+			self.found = true;
+		}
+		syn::visit_mut::visit_type_reference_mut(self, reference);
+	}
+
+	fn visit_lifetime_mut(&mut self, lifetime: &mut Lifetime) {
+		if lifetime.ident == "_" {
+			*lifetime = props_lifetime();
+			self.found = true;
+		}
+	}
+
+	// Lifetimes inside `fn(&T)` and `dyn Fn(&T)` are bound by the pointer
+	// itself and must stay elided.
+	fn visit_type_fn_ptr_mut(&mut self, _: &mut syn::TypeFnPtr) {}
+
+	fn visit_parenthesized_generic_arguments_mut(
+		&mut self,
+		_: &mut syn::ParenthesizedGenericArguments,
+	) {
+	}
+}
+
+fn to_pascal_case(s: &str) -> String {
+	s.split('_')
+		.map(|word| {
+			let mut chars = word.chars();
+			match chars.next() {
+				Some(first) => first.to_ascii_uppercase().to_string() + chars.as_str(),
+				None => String::new(),
+			}
+		})
+		.collect()
+}
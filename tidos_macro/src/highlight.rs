@@ -0,0 +1,288 @@
+use crate::source_file::SourceFile;
+use proc_macro2::{Ident, TokenStream};
+use quote::{quote, ToTokens, TokenStreamExt};
+use std::collections::HashMap;
+use std::sync::LazyLock;
+use syn::parse::{Parse, ParseStream};
+use syn::{LitStr, Token};
+use syntect::parsing::syntax_definition::{Context, MatchOperation, MatchPattern, Pattern};
+use syntect::parsing::{
+	ParseState, Regex, Scope, ScopeStack, SyntaxDefinition, SyntaxReference, SyntaxSet,
+};
+
+/// `highlight!("rust", file = "./snippet.rs")` or
+/// `highlight!("rust", "fn main() {}")`, highlighted while parsing.
+pub struct Highlight {
+	file: Option<SourceFile>,
+	html: String,
+}
+
+/// The grammars shipped with syntect, plus TOML which it lacks.
+static SYNTAXES: LazyLock<SyntaxSet> = LazyLock::new(|| {
+	let mut builder = SyntaxSet::load_defaults_newlines().into_builder();
+	builder.add(toml_syntax());
+	builder.build()
+});
+
+/// Token classes, matched against a token's scopes from the innermost one
+/// outwards. Scopes without a class fall through to their parent, so the
+/// quotes of a string are still part of the string.
+const CLASSES: &[(&str, &str)] = &[
+	("comment", "hl-comment"),
+	("constant.character.escape", "hl-escape"),
+	("string", "hl-string"),
+	("constant.numeric", "hl-number"),
+	("constant", "hl-constant"),
+	("keyword.operator", "hl-operator"),
+	("keyword", "hl-keyword"),
+	("storage", "hl-keyword"),
+	("variable.language", "hl-keyword"),
+	("support.macro", "hl-macro"),
+	("entity.name.function", "hl-function"),
+	("support.function", "hl-function"),
+	("variable.function", "hl-function"),
+	("entity.name.tag", "hl-tag"),
+	("entity.other.attribute-name", "hl-attribute"),
+	("entity.name", "hl-type"),
+	("entity.other.inherited-class", "hl-type"),
+	("support.type", "hl-type"),
+	("support.class", "hl-type"),
+	("variable", "hl-variable"),
+];
+
+impl Parse for Highlight {
+	fn parse(input: ParseStream) -> syn::Result<Self> {
+		let language = input.parse::<LitStr>().map_err(|error| {
+			syn::Error::new(
+				error.span(),
+				"expected a language, e.g. `highlight!(\"rust\", file = \"./snippet.rs\")`",
+			)
+		})?;
+		input.parse::<Token![,]>()?;
+		let is_file = input.peek(syn::Ident) && input.peek2(Token![=]);
+		if is_file {
+			let key = input.parse::<Ident>()?;
+			if key != "file" {
+				return Err(syn::Error::new(
+					key.span(),
+					"expected `file = \"./snippet.rs\"` or the code to highlight",
+				));
+			}
+			input.parse::<Token![=]>()?;
+		}
+		let source = input.parse::<LitStr>().map_err(|error| {
+			syn::Error::new(
+				error.span(),
+				if is_file {
+					"expected the path to the file as a string literal"
+				} else {
+					"expected `file = \"./snippet.rs\"` or the code to highlight as a string literal"
+				},
+			)
+		})?;
+		input.parse::<Option<Token![,]>>()?;
+		if !input.is_empty() {
+			return Err(input.error("unexpected tokens after the code to highlight"));
+		}
+
+		let syntax = find_syntax(&language.value()).ok_or_else(|| {
+			syn::Error::new(
+				language.span(),
+				format!(
+					"no grammar for `{}`, expected e.g. `rust`, `toml`, `sh`, `js` or `html`",
+					language.value()
+				),
+			)
+		})?;
+
+		let file = if is_file {
+			Some(SourceFile::read(&source)?)
+		} else {
+			None
+		};
+		let value = source.value();
+		let code = file.as_ref().map_or(value.as_str(), |file| &file.contents);
+		let html = highlight(code, syntax).map_err(|error| {
+			syn::Error::new(source.span(), format!("couldn't highlight: {error}"))
+		})?;
+
+		Ok(Highlight { file, html })
+	}
+}
+
+impl ToTokens for Highlight {
+	fn to_tokens(&self, tokens: &mut TokenStream) {
+		let html = &self.html;
+		let markup = quote! { tidos::Markup::trusted_static(#html) };
+		match &self.file {
+			Some(file) => {
+				let track = file.track();
+				tokens.append_all(quote! { { #track #markup } });
+			}
+			None => markup.to_tokens(tokens),
+		}
+	}
+}
+
+fn find_syntax(language: &str) -> Option<&'static SyntaxReference> {
+	let token = match language.to_ascii_lowercase().as_str() {
+		"rust" | "rs" => "rs".to_string(),
+		"shell" | "sh" | "bash" | "zsh" | "console" => "sh".to_string(),
+		"javascript" | "js" | "mjs" => "js".to_string(),
+		"text" | "plain" | "txt" => "txt".to_string(),
+		other => other.to_string(),
+	};
+	SYNTAXES.find_syntax_by_token(&token)
+}
+
+/// Wrap every token of `code` in a `<span>` with its class, escaping the text.
+/// Spans never cross a line break, so the output can be split into lines.
+fn highlight(code: &str, syntax: &SyntaxReference) -> Result<String, syntect::Error> {
+	let mut state = ParseState::new(syntax);
+	let mut stack = ScopeStack::new();
+	let mut spans = Spans::default();
+
+	for line in code.split_inclusive('\n') {
+		let ops = state.parse_line(line, &SYNTAXES)?;
+		let content = line.trim_end_matches(['\n', '\r']);
+		let mut start = 0;
+		for (index, op) in ops {
+			let index = index.min(content.len());
+			if index > start {
+				spans.push(&content[start..index], class(&stack));
+				start = index;
+			}
+			stack.apply(&op)?;
+		}
+		spans.push(&content[start..], class(&stack));
+		spans.close();
+		spans.html.push_str(&line[content.len()..]);
+	}
+
+	Ok(spans.html)
+}
+
+fn class(stack: &ScopeStack) -> Option<&'static str> {
+	stack.as_slice().iter().rev().find_map(|scope| {
+		let name = scope.build_string();
+		CLASSES
+			.iter()
+			.find(|(prefix, _)| {
+				name.strip_prefix(prefix)
+					.is_some_and(|rest| rest.is_empty() || rest.starts_with('.'))
+			})
+			.map(|(_, class)| *class)
+	})
+}
+
+/// Output buffer that merges neighbouring tokens of the same class.
+#[derive(Default)]
+struct Spans {
+	html: String,
+	open: Option<&'static str>,
+}
+
+impl Spans {
+	fn push(&mut self, text: &str, class: Option<&'static str>) {
+		if text.is_empty() {
+			return;
+		}
+		if self.open != class {
+			self.close();
+			if let Some(class) = class {
+				self.html.push_str("<span class=\"");
+				self.html.push_str(class);
+				self.html.push_str("\">");
+			}
+			self.open = class;
+		}
+		for char in text.chars() {
+			match char {
+				'&' => self.html.push_str("&amp;"),
+				'<' => self.html.push_str("&lt;"),
+				'>' => self.html.push_str("&gt;"),
+				'"' => self.html.push_str("&quot;"),
+				_ => self.html.push(char),
+			}
+		}
+	}
+
+	fn close(&mut self) {
+		if self.open.take().is_some() {
+			self.html.push_str("</span>");
+		}
+	}
+}
+
+/// syntect doesn't ship a TOML grammar. This one works line by line, so
+/// multi-line strings are only highlighted on their opening line.
+fn toml_syntax() -> SyntaxDefinition {
+	const KEY: &str = r#"(?:[A-Za-z0-9_-]+|"[^"]*"|'[^']*')"#;
+	let patterns = [
+		(r"#.*", vec!["comment.line.number-sign.toml"]),
+		(
+			r"^\s*(\[\[?)([^\]]*)(\]\]?)",
+			vec![
+				"meta.section.toml",
+				"punctuation.section.table.begin.toml",
+				"entity.name.section.toml",
+				"punctuation.section.table.end.toml",
+			],
+		),
+		(
+			&format!(r"(?:^|(?<=[{{,]))\s*({KEY}(?:\s*\.\s*{KEY})*)\s*(=)"),
+			vec![
+				"meta.key-value.toml",
+				"entity.other.attribute-name.toml",
+				"keyword.operator.assignment.toml",
+			],
+		),
+		(r#""(?:[^"\\]|\\.)*""#, vec!["string.quoted.double.toml"]),
+		(r"'[^']*'", vec!["string.quoted.single.toml"]),
+		(
+			r"\b\d{4}-\d{2}-\d{2}(?:[T ]\d{2}:\d{2}(?::\d{2}(?:\.\d+)?)?(?:Z|[+-]\d{2}:\d{2})?)?",
+			vec!["constant.other.datetime.toml"],
+		),
+		(
+			r"[+-]?\b(?:0x[0-9A-Fa-f_]+|0o[0-7_]+|0b[01_]+|\d[\d_]*(?:\.\d[\d_]*)?(?:[eE][+-]?\d+)?)\b|[+-]?\b(?:inf|nan)\b",
+			vec!["constant.numeric.toml"],
+		),
+		(
+			r"\b(?:true|false)\b",
+			vec!["constant.language.boolean.toml"],
+		),
+	];
+
+	let scope = |name: &str| Scope::new(name).expect("valid TOML scope");
+	let mut context = Context::new(false);
+	context.meta_content_scope = vec![scope("source.toml")];
+	context.patterns = patterns
+		.into_iter()
+		.map(|(regex, scopes)| {
+			let (scope_name, captures) = scopes.split_first().expect("a scope per pattern");
+			let captures = captures
+				.iter()
+				.enumerate()
+				.map(|(index, name)| (index + 1, vec![scope(name)]))
+				.collect::<Vec<_>>();
+			Pattern::Match(MatchPattern {
+				has_captures: !captures.is_empty(),
+				regex: Regex::new(regex.to_string()),
+				scope: vec![scope(scope_name)],
+				captures: (!captures.is_empty()).then_some(captures),
+				operation: MatchOperation::None,
+				with_prototype: None,
+			})
+		})
+		.collect();
+
+	SyntaxDefinition {
+		name: "TOML".to_string(),
+		file_extensions: vec!["toml".to_string()],
+		scope: scope("source.toml"),
+		first_line_match: None,
+		hidden: false,
+		variables: HashMap::new(),
+		contexts: HashMap::from([("__start".to_string(), context)]),
+	}
+}
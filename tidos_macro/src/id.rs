@@ -0,0 +1,78 @@
+use std::path::{Path, PathBuf};
+use xxhash_rust::xxh3::Xxh3;
+
+/// A deterministic id such as `tidos-card-3fa1c2d0`, hashed from the calling
+/// crate's name and `parts`. The same inputs give the same id on every build
+/// and machine, so class names and head keys are reproducible and stay
+/// cacheable between deploys.
+///
+/// `name` is an optional human-readable part, reduced to lowercase ASCII
+/// letters, digits and dashes.
+pub fn stable_id(name: Option<&str>, parts: &[&[u8]]) -> String {
+	let mut hasher = Xxh3::new();
+	let krate = std::env::var("CARGO_PKG_NAME").unwrap_or_default();
+	for part in std::iter::once(krate.as_bytes()).chain(parts.iter().copied()) {
+		// Length-prefixed, so `["ab", "c"]` and `["a", "bc"]` differ.
+		hasher.update(&(part.len() as u64).to_le_bytes());
+		hasher.update(part);
+	}
+	let hash = hasher.digest() as u32;
+
+	let name = name.map(readable).unwrap_or_default();
+	if name.is_empty() {
+		format!("tidos-{hash:08x}")
+	} else {
+		format!("tidos-{name}-{hash:08x}")
+	}
+}
+
+/// Where the macro is called, as `path:line:column` with the path relative
+/// to the calling crate, so it doesn't depend on where the crate is built.
+pub fn call_site() -> String {
+	let span = proc_macro::Span::call_site();
+	let file = call_site_file()
+		.map(|file| crate_relative(&file))
+		.unwrap_or_default();
+	format!("{file}:{}:{}", span.line(), span.column())
+}
+
+/// The source file the macro is called from.
+pub fn call_site_file() -> Option<PathBuf> {
+	proc_macro::Span::call_site().local_file()
+}
+
+/// A readable name for the module in `file`, e.g. `card` for both
+/// `card.rs` and `card/mod.rs`.
+pub fn module_name(file: &Path) -> Option<&str> {
+	let stem = file.file_stem()?.to_str()?;
+	if matches!(stem, "mod" | "lib" | "main") {
+		file.parent()?.file_name()?.to_str()
+	} else {
+		Some(stem)
+	}
+}
+
+/// `path` relative to the calling crate's manifest, with `/` separators.
+pub fn crate_relative(path: &Path) -> String {
+	let path = std::path::absolute(path).unwrap_or_else(|_| path.to_path_buf());
+	let relative = std::env::var_os("CARGO_MANIFEST_DIR")
+		.and_then(|manifest_dir| path.strip_prefix(manifest_dir).ok())
+		.unwrap_or(&path);
+	relative
+		.components()
+		.map(|component| component.as_os_str().to_string_lossy())
+		.collect::<Vec<_>>()
+		.join("/")
+}
+
+fn readable(name: &str) -> String {
+	let mut readable = String::new();
+	for char in name.chars() {
+		if char.is_ascii_alphanumeric() {
+			readable.push(char.to_ascii_lowercase());
+		} else if !readable.is_empty() && !readable.ends_with('-') {
+			readable.push('-');
+		}
+	}
+	readable.trim_end_matches('-').to_string()
+}
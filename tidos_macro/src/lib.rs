@@ -11,17 +11,42 @@ extern crate proc_macro;
 
 use proc_macro::TokenStream;
 
+#[cfg(feature = "assets")]
+use crate::asset::Asset;
+use crate::function_component::FunctionComponent;
+#[cfg(feature = "highlight")]
+use crate::highlight::Highlight;
+#[cfg(feature = "markdown")]
+use crate::markdown::MarkdownFile;
 use crate::page_wrapper::PageWrapper;
+use crate::props::PropsDerive;
+use crate::scoped_css::ScopedCss;
+use crate::view_file::ViewFile;
 use quote::{format_ident, quote, ToTokens};
 use syn::parse_macro_input;
 use tokens::Component;
-use uuid::Uuid;
 
+#[cfg(feature = "assets")]
+mod asset;
 mod code_generation;
+mod config;
+mod css;
+mod function_component;
+#[cfg(feature = "highlight")]
+mod highlight;
 mod i18n;
+mod id;
+#[cfg(feature = "markdown")]
+mod markdown;
 mod page_wrapper;
 mod parsing;
+mod props;
+mod scoped_css;
+#[cfg(feature = "scss")]
+mod scss;
+mod source_file;
 mod tokens;
+mod view_file;
 
 #[doc = include_str!("view.md")]
 #[allow(clippy::all)]
@@ -35,6 +60,55 @@ pub fn view(input: TokenStream) -> TokenStream {
 	expanded.into()
 }
 
+/// Renders an HTML template read from a file.
+///
+/// Works like [`view!`] with the template moved out of the Rust source, so
+/// large layouts stay reviewable and can be edited without touching Rust.
+/// The path is resolved relative to the calling source file, like
+/// `include_str!`, and the crate is rebuilt when the file changes.
+///
+/// The file holds exactly what would go between the braces of `view!`, and
+/// expressions in it see the variables in scope at the call, including
+/// `self` and `page`. Syntax errors point at the line and column in the
+/// template.
+///
+/// # Example
+///
+/// `src/layout.tidos`:
+///
+/// ```text
+/// <header>
+///     <h1>{&self.title}</h1>
+/// </header>
+/// <main>
+///     @slot{self.content}
+/// </main>
+/// ```
+///
+/// `src/layout.rs`:
+///
+/// ```rust,ignore
+/// use tidos::{view_file, Component, Page, Props, Slot};
+///
+/// #[derive(Props)]
+/// pub struct Layout<'a> {
+///     pub title: String,
+///     pub content: Slot<'a>,
+/// }
+///
+/// impl Component for Layout<'_> {
+///     fn to_render(&self, page: &mut Page) {
+///         view_file!("./layout.tidos");
+///     }
+/// }
+/// ```
+#[proc_macro]
+pub fn view_file(input: TokenStream) -> TokenStream {
+	parse_macro_input!(input as ViewFile)
+		.to_token_stream()
+		.into()
+}
+
 /// Renders an HTML template and wraps it in a `Page`.
 ///
 /// Use `page!` in a route handler instead of [`view!`] when you need to
@@ -54,7 +128,7 @@ pub fn view(input: TokenStream) -> TokenStream {
 ///     page! {
 ///         <main>
 ///             <h1>Getting started</h1>
-///             <p>{x.to_string()}</p>
+///             <p>{x}</p>
 ///         </main>
 ///     }
 /// }
@@ -73,8 +147,8 @@ pub fn page(input: TokenStream) -> TokenStream {
 ///
 /// Call `head!` inside `Component::to_render` to add elements such as
 /// `<title>`, `<link>`, or `<meta>` tags to the page `<head>`. Each call is
-/// deduplicated by a compile-time UUID, so it is safe to call from components
-/// rendered inside a loop.
+/// deduplicated by an id hashed from the call site and its contents, so it is
+/// safe to call from components rendered inside a loop.
 ///
 /// # Example
 ///
@@ -97,9 +171,12 @@ pub fn page(input: TokenStream) -> TokenStream {
 #[allow(clippy::all)]
 #[proc_macro]
 pub fn head(input: TokenStream) -> TokenStream {
+	let x = id::stable_id(
+		None,
+		&[id::call_site().as_bytes(), input.to_string().as_bytes()],
+	);
 	let input = parse_macro_input!(input as Component);
 
-	let x = Uuid::new_v4().to_string();
 	let push_stmts = input.to_token_stream();
 	let expanded = quote! {
 		if page.head_ids.insert(#x) {
@@ -115,11 +192,29 @@ pub fn head(input: TokenStream) -> TokenStream {
 /// Injects a scoped CSS file into the page `<head>` and returns the generated class name.
 ///
 /// The path is resolved relative to the calling source file (like
-/// `include_str!`). At compile time the macro generates a unique
-/// `tidos-<uuid>` class name, wraps the CSS file content as
-/// `.tidos-<uuid> { … }`, and injects a `<style>` tag into the page `<head>`
-/// via `Page::add_elements_to_head`. Because injection is keyed by UUID,
-/// calling `scoped_css!` inside a loop only injects the style once.
+/// `include_str!`). At compile time the macro derives an id such as
+/// `tidos-card-3fa1c2d0` from the file name and a hash of the crate name, the
+/// file's path and its contents, so it is the same on every build. The
+/// stylesheet is rewritten so every selector requires a `data-<id>`
+/// attribute, which `view!` adds to each HTML element it
+/// writes. The rules therefore only match the view's own elements, not the
+/// page around it nor the elements rendered by child components. Elements
+/// passed to a child component's slot are written by the view, so they are
+/// styled.
+///
+/// - `&` at the top level is the element the returned class is assigned to.
+/// - `:global(selector)` leaves a selector unscoped, e.g.
+///   `& :global(.markdown p)` styles paragraphs rendered by a child.
+/// - `@keyframes` names are prefixed with the id, along with the `animation`
+///   and `animation-name` declarations that refer to them.
+/// - `@media`, `@supports`, `@container` and `@layer` blocks are scoped too.
+///
+/// The `<style>` tag is injected via `Page::add_elements_to_head`, keyed by
+/// the id, so calling `scoped_css!` inside a loop only injects it once.
+/// When a view uses several stylesheets, each one styles the element it's
+/// assigned to and the elements inside it. Outside of `view!` no elements get
+/// the attribute, so a stylesheet with rules other than `&` (and
+/// `:global(…)`) is a compile error there.
 ///
 /// The macro returns a `&'static str` you can bind to a `class` attribute.
 ///
@@ -140,20 +235,394 @@ pub fn head(input: TokenStream) -> TokenStream {
 ///     }
 /// }
 /// ```
-#[allow(clippy::all)]
+///
+/// With `card.css`:
+///
+/// ```css
+/// & { border-radius: 8px; }
+/// h2 { color: #a8dadc; }
+/// :global(body.dark) h2 { color: white; }
+/// ```
+///
+/// # Inline styles
+///
+/// Small components can write the CSS in the macro instead, either as tokens
+/// or as a string. A string names a file if one exists at that path, and is
+/// otherwise CSS when it has a `{ … }` block or a `;`. Inline CSS is checked
+/// while compiling, and errors point at the offending token. The id is named
+/// after the module, e.g. `tidos-card-…` for `card/mod.rs`.
+///
+/// ```rust,ignore
+/// view! {
+///     <div class={scoped_css! {
+///         & { border-radius: 8px; }
+///         h2:hover { color: #a8dadc; }
+///     }}>
+///         <h2>{&self.title}</h2>
+///     </div>
+/// }
+/// ```
+///
+/// Rust has to be able to tokenize the CSS, so single-quoted strings and
+/// colours such as `#2ea` need the string form:
+/// `scoped_css!(r#"& { font-family: 'Inter'; }"#)`.
+///
+/// # Custom properties
+///
+/// Values from Rust follow the stylesheet as `name = expr`, and are set as
+/// CSS custom properties in the `style` of the element the class is assigned
+/// to, so the stylesheet itself stays static and is injected once.
+/// `accent_colour = …` becomes `--accent-colour`. The values can be anything
+/// implementing `Display`, and are escaped: characters other than letters,
+/// digits, spaces and `# . , % + - _` are written as CSS escapes, so a value
+/// can't call `url(…)` or start a comment. Use functions such as `calc(…)` in
+/// the stylesheet instead.
+///
+/// ```rust,ignore
+/// view! {
+///     <div class={scoped_css!("./bar.css", progress = self.pct, accent = &self.color)}></div>
+/// }
+/// // <div class="tidos-bar-…" style="--progress:42;--accent:#e94560;" …>
+/// ```
+///
+/// A `style` the element already has is kept after the properties. This only
+/// works inside `view!`, where the macro knows the element.
+///
+/// # Validation and output
+///
+/// Stylesheet files are checked while compiling too. Unclosed blocks and
+/// strings, rules without a selector, dangling `,` and combinators, and
+/// declarations without a value fail the build, with the file, line and
+/// column of the mistake. The injected CSS is minified: comments and
+/// redundant whitespace are dropped, `0.5` is written as `.5` and `#ffcc00`
+/// as `#fc0`.
+///
+/// Vendor prefixes are added for the browsers listed under `[css.targets]`
+/// in a `Tidos.toml` next to the crate's `Cargo.toml`. A target is the
+/// oldest version to support; `edge` counts as Chrome and `ios_safari` as
+/// Safari:
+///
+/// ```toml
+/// [css.targets]
+/// safari = 14
+/// firefox = 78
+/// ```
+///
+/// With those targets `user-select: none` is written as
+/// `-webkit-user-select:none;user-select:none`. Without the table no
+/// prefixes are added.
+///
+/// # Extracted stylesheet
+///
+/// With the `extract-css` feature the CSS isn't inlined. It is registered
+/// for `tidos::styles`, which serves the scoped CSS of every view as one
+/// cacheable file, and the page head gets a `<link>` to that file instead.
 #[proc_macro]
 pub fn scoped_css(input: TokenStream) -> TokenStream {
-	// let file_name = input.to_string().replace("\"", "");
-	let file_name: proc_macro2::TokenStream = input.into();
-
-	let x = format!("tidos-{}", Uuid::new_v4().to_string());
-	// todo refactor it to &str when I'm comfortable with lifetime annontations
-	let input = quote!(
-		{
-			page.add_elements_to_head(#x, String::from(concat!("<style>.", #x, " {", include_str!(#file_name), "}</style>")));
-			#x
-		}
-	);
+	parse_macro_input!(input as ScopedCss)
+		.to_token_stream()
+		.into()
+}
+
+/// Compiles an SCSS file at compile time and injects it like
+/// [`scoped_css!`](macro@scoped_css), returning the generated class name.
+///
+/// The file is compiled with [grass](https://docs.rs/grass), a pure-Rust Sass
+/// implementation, so variables, mixins, nesting and functions all work.
+/// `@use` and `@import` resolve relative to the file, and the crate is
+/// rebuilt when the file or any partial it uses changes. Sass errors fail
+/// the build, quoting the line they're on.
+///
+/// The compiled CSS is scoped, minified and injected exactly like
+/// `scoped_css!`: `&` at the top level is the element with the returned
+/// class, `:global(…)` opts out of scoping, and a view with several
+/// stylesheets scopes each to the element it's assigned to.
+///
+/// Requires the `scss` feature.
+///
+/// ```rust,ignore
+/// view! {
+///     <div class={scoped_scss!("./card.scss")}>
+///         <h2>{&self.title}</h2>
+///     </div>
+/// }
+/// ```
+///
+/// With `card.scss`:
+///
+/// ```scss
+/// @use "../theme" as theme;
+///
+/// & {
+///     @include theme.rounded;
+///     h2 { color: theme.$accent; }
+/// }
+/// ```
+#[proc_macro]
+pub fn scoped_scss(input: TokenStream) -> TokenStream {
+	parse_macro_input!(input with ScopedCss::parse_scss)
+		.to_token_stream()
+		.into()
+}
+
+/// Converts a Markdown file to HTML at compile time.
+///
+/// The path is resolved relative to the calling source file, like
+/// [`scoped_css!`](macro@scoped_css), and the crate is rebuilt when the file
+/// changes. Returns a [`tidos::markdown::Markdown`] that renders its HTML when
+/// interpolated with `{expr}`, and offers a table of contents with
+/// `.toc()`.
+///
+/// Every heading gets an `id` derived from its text, e.g.
+/// `## Getting started` becomes `<h2 id="getting-started">`, unless it sets
+/// one explicitly with `## Getting started {#start}`. Tables, footnotes,
+/// strikethrough and task lists are supported. The file is trusted, so raw
+/// HTML in it is kept as-is.
+///
+/// Requires the `markdown` feature.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use tidos::markdown::Markdown;
+/// use tidos::{markdown, view, Component, Page};
+///
+/// static RELEASE_NOTES: Markdown = markdown!("./release_notes.md");
+///
+/// pub struct ReleaseNotes;
+///
+/// impl Component for ReleaseNotes {
+///     fn to_render(&self, page: &mut Page) {
+///         view! {
+///             <nav>{RELEASE_NOTES.toc()}</nav>
+///             <article>{RELEASE_NOTES}</article>
+///         }
+///     }
+/// }
+/// ```
+#[cfg(feature = "markdown")]
+#[proc_macro]
+pub fn markdown(input: TokenStream) -> TokenStream {
+	parse_macro_input!(input as MarkdownFile)
+		.to_token_stream()
+		.into()
+}
+
+/// Syntax highlights code at compile time.
+///
+/// The first argument names the language: `rust`, `toml`, `sh`, `js`,
+/// `html`, or any other file extension syntect ships a grammar for. The
+/// second is the code itself, or `file = "./install.rs"` to read a file,
+/// resolved relative to the calling source file and tracked for rebuilds.
+///
+/// Returns a [`tidos::Markup`] of escaped HTML in which every token is
+/// wrapped in a `<span>` with a class such as `hl-keyword` or `hl-string`,
+/// so `{…}` renders it as-is. It can be assigned to a `static`. Spans never
+/// cross a line break, so the result can be split into lines. Style the
+/// classes with [`tidos::highlight::STYLESHEET`] or your own theme.
+///
+/// Requires the `highlight` feature.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use tidos::{highlight, view, Component, Markup, Page};
+///
+/// static CARGO: Markup = highlight!("toml", r#"tidos = { version = "0.7.2" }"#);
+///
+/// pub struct Install;
+///
+/// impl Component for Install {
+///     fn to_render(&self, page: &mut Page) {
+///         let snippet = highlight!("rust", file = "./install.rs");
+///         view! {
+///             <pre><code>{snippet}</code></pre>
+///             <pre><code>{CARGO}</code></pre>
+///         }
+///     }
+/// }
+/// ```
+#[cfg(feature = "highlight")]
+#[proc_macro]
+pub fn highlight(input: TokenStream) -> TokenStream {
+	parse_macro_input!(input as Highlight)
+		.to_token_stream()
+		.into()
+}
+
+/// Embeds a static file and returns its fingerprinted URL.
+///
+/// The path is resolved relative to the calling source file, like
+/// [`scoped_css!`](macro@scoped_css). The file is hashed while compiling and
+/// served at a URL containing the hash, e.g. `/assets/logo.3fa1c2d0.svg` for
+/// `logo.svg`, so the URL changes whenever the file does and browsers can
+/// cache it forever. The file is included in the binary and registered in
+/// `tidos::assets`, whose integrations serve it with an immutable
+/// `Cache-Control`.
+///
+/// Returns a `&'static str`. Requires the `assets` feature.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use tidos::{asset, view, Component, Page};
+///
+/// pub struct Logo;
+///
+/// impl Component for Logo {
+///     fn to_render(&self, page: &mut Page) {
+///         view! {
+///             <img src={asset!("../public/logo.svg")} alt="Logo" />
+///         }
+///     }
+/// }
+/// ```
+#[cfg(feature = "assets")]
+#[proc_macro]
+pub fn asset(input: TokenStream) -> TokenStream {
+	parse_macro_input!(input as Asset).to_token_stream().into()
+}
+
+/// Turns a function into a component.
+///
+/// The function's parameters become the fields of a props struct with the
+/// same name as the function, and its body becomes the
+/// [`Component::to_render`](tidos::Component::to_render) implementation.
+/// Inside the body every parameter is available as a reference to the prop,
+/// and `page` refers to the page being rendered, so `view!` works as usual.
+///
+/// Elided lifetimes in parameter types, such as `&str` or `Slot<'_>`, are
+/// given a `'props` lifetime on the generated struct. Generic parameters and
+/// where clauses are carried over as-is.
+///
+/// The props struct derives [`Props`](tidos::Props), so parameters follow
+/// the same rules: `Option` parameters may be left out, and so may parameters
+/// marked `#[default]`, which fall back to [`Default::default()`]. Other
+/// `#[prop(…)]` options can be put on parameters as well.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use tidos::{component, view};
+///
+/// #[derive(Default)]
+/// pub enum Tone {
+///     #[default]
+///     Neutral,
+///     Warning,
+/// }
+///
+/// #[component]
+/// pub fn Badge(label: &str, #[default] tone: Tone) {
+///     let class = match tone {
+///         Tone::Neutral => "badge",
+///         Tone::Warning => "badge badge-warning",
+///     };
+///     view! {
+///         <span class={class}>{label}</span>
+///     }
+/// }
+///
+/// // Used like any other component:
+/// // view! {
+/// //     <Badge label="New" tone={Tone::Warning} />
+/// //     <Badge label="Draft" />
+/// // }
+/// ```
+///
+/// Generates roughly:
+///
+/// ```rust,no_run
+/// # use tidos::{view, Props};
+/// # #[derive(Default)] pub enum Tone { #[default] Neutral, Warning }
+/// #[derive(Props)]
+/// pub struct Badge<'props> {
+///     pub label: &'props str,
+///     #[prop(optional)]
+///     pub tone: Tone,
+/// }
+///
+/// impl tidos::Component for Badge<'_> {
+///     fn to_render(&self, page: &mut tidos::Page) {
+///         let Self { label, tone } = self;
+///         // function body
+///     }
+/// }
+/// ```
+#[allow(clippy::all)]
+#[proc_macro_attribute]
+pub fn component(args: TokenStream, input: TokenStream) -> TokenStream {
+	if !args.is_empty() {
+		return syn::Error::new_spanned(
+			proc_macro2::TokenStream::from(args),
+			"`#[component]` takes no arguments",
+		)
+		.to_compile_error()
+		.into();
+	}
+	let input = parse_macro_input!(input as FunctionComponent);
+
+	let expanded = input.to_token_stream();
+
+	expanded.into()
+}
+
+/// Derives [`Props`](tidos::Props), the builder `view!` uses to construct a
+/// component from its tag.
+///
+/// Fields are required unless they are an `Option`, or are marked with one
+/// of the `#[prop(…)]` options:
+///
+/// - `#[prop(optional)]` — falls back to `Default::default()`
+/// - `#[prop(default = expr)]` — falls back to `expr`
+/// - `#[prop(into)]` — the setter accepts any `impl Into<T>`
+///
+/// Leaving out a required prop fails to compile with an error that names it.
+/// `view!` converts each attribute value with [`IntoProp`](tidos::IntoProp)
+/// before passing it on, so `href="/news"` fills a `String` even without
+/// `#[prop(into)]`. Numbers and `Option`s of numbers are the exception: they
+/// need the field's type, so that literals such as `count={5}` infer it, and
+/// a `u8` isn't widened into a `u32` prop.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use tidos::{view, Component, Page, Props};
+///
+/// #[derive(Props)]
+/// pub struct Link {
+///     #[prop(into)]
+///     pub href: String,
+///     pub label: String,
+///     #[prop(default = "_self")]
+///     pub target: &'static str,
+///     pub title: Option<String>,
+/// }
+///
+/// impl Component for Link {
+///     fn to_render(&self, page: &mut Page) {
+///         view! {
+///             <a href={&self.href} target={self.target} title={&self.title}>{&self.label}</a>
+///         }
+///     }
+/// }
+///
+/// // view! { <Link href="/news" label="News" /> }
+/// ```
+///
+/// Generates a `LinkBuilder` with one setter per field, which can be used
+/// directly as well:
+///
+/// ```rust,ignore
+/// Link::builder()
+///     .href("/news")
+///     .label(String::from("News"))
+///     .build()
+/// ```
+#[allow(clippy::all)]
+#[proc_macro_derive(Props, attributes(prop))]
+pub fn derive_props(input: TokenStream) -> TokenStream {
+	let input = parse_macro_input!(input as PropsDerive);
 
 	let expanded = input.to_token_stream();
 
@@ -171,6 +640,9 @@ pub fn scoped_css(input: TokenStream) -> TokenStream {
 /// 2. Renders the kebab-case HTML tag derived from the struct name, forwarding
 ///    all fields as HTML attributes.
 ///
+/// The struct also derives [`Props`](tidos::Props), so it can be used as a
+/// component tag in `view!` straight away.
+///
 /// # Attribute mapping
 ///
 /// Field names are converted from `snake_case` to `kebab-case`.
@@ -268,6 +740,7 @@ pub fn native_element(_args: TokenStream, input: TokenStream) -> TokenStream {
 		.collect();
 
 	let expanded = quote! {
+		#[derive(tidos::Props)]
 		#input_struct
 
 		impl tidos::Component for #struct_name {
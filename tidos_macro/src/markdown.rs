@@ -0,0 +1,206 @@
+use crate::source_file::SourceFile;
+use proc_macro2::TokenStream;
+use pulldown_cmark::{CowStr, Event, Options, Parser, Tag, TagEnd};
+use quote::{quote, ToTokens, TokenStreamExt};
+use std::collections::HashSet;
+use syn::parse::{Parse, ParseStream};
+use syn::LitStr;
+
+/// `markdown!("./page.md")`, converted to HTML while parsing.
+pub struct MarkdownFile {
+	file: SourceFile,
+	html: String,
+	toc: String,
+	headings: Vec<Heading>,
+}
+
+struct Heading {
+	level: u8,
+	id: String,
+	title: String,
+}
+
+impl Parse for MarkdownFile {
+	fn parse(input: ParseStream) -> syn::Result<Self> {
+		let path = input.parse::<LitStr>().map_err(|error| {
+			syn::Error::new(
+				error.span(),
+				"expected the path to a Markdown file, e.g. `markdown!(\"./page.md\")`",
+			)
+		})?;
+		if !input.is_empty() {
+			return Err(input.error("unexpected tokens after the Markdown file path"));
+		}
+
+		let file = SourceFile::read(&path)?;
+		let (html, headings) = to_html(&file.contents);
+		let toc = table_of_contents(&headings);
+
+		Ok(MarkdownFile {
+			file,
+			html,
+			toc,
+			headings,
+		})
+	}
+}
+
+impl ToTokens for MarkdownFile {
+	fn to_tokens(&self, tokens: &mut TokenStream) {
+		let MarkdownFile {
+			file,
+			html,
+			toc,
+			headings,
+		} = self;
+		let track = file.track();
+		let headings = headings.iter().map(|Heading { level, id, title }| {
+			quote! { tidos::markdown::Heading { level: #level, id: #id, title: #title } }
+		});
+
+		tokens.append_all(quote! {
+			{
+				#track
+				tidos::markdown::Markdown::trusted_static(#html, #toc, &[ #( #headings ),* ])
+			}
+		});
+	}
+}
+
+/// Render `markdown`, giving every heading an `id`.
+fn to_html(markdown: &str) -> (String, Vec<Heading>) {
+	let options = Options::ENABLE_TABLES
+		| Options::ENABLE_FOOTNOTES
+		| Options::ENABLE_STRIKETHROUGH
+		| Options::ENABLE_TASKLISTS
+		| Options::ENABLE_HEADING_ATTRIBUTES;
+	let mut events = Parser::new_ext(markdown, options).collect::<Vec<_>>();
+
+	// Explicit ids, `## Title {#id}`, are reserved before generating the others.
+	let mut used_ids = events
+		.iter()
+		.filter_map(|event| match event {
+			Event::Start(Tag::Heading { id: Some(id), .. }) => Some(id.to_string()),
+			_ => None,
+		})
+		.collect::<HashSet<_>>();
+
+	let mut headings = vec![];
+	let mut index = 0;
+	while index < events.len() {
+		let Event::Start(Tag::Heading { level, .. }) = &events[index] else {
+			index += 1;
+			continue;
+		};
+		let level = *level as u8;
+
+		let mut title = String::new();
+		let mut end = index + 1;
+		while !matches!(events[end], Event::End(TagEnd::Heading(_))) {
+			if let Event::Text(text) | Event::Code(text) = &events[end] {
+				title.push_str(text);
+			}
+			end += 1;
+		}
+
+		let Event::Start(Tag::Heading { id, .. }) = &mut events[index] else {
+			unreachable!()
+		};
+		let id = match id {
+			Some(id) => id.to_string(),
+			None => {
+				let slug = unique_id(&slugify(&title), &mut used_ids);
+				*id = Some(CowStr::from(slug.clone()));
+				slug
+			}
+		};
+
+		headings.push(Heading { level, id, title });
+		index = end + 1;
+	}
+
+	let mut html = String::with_capacity(markdown.len() * 3 / 2);
+	pulldown_cmark::html::push_html(&mut html, events.into_iter());
+	(html, headings)
+}
+
+/// `Getting started!` becomes `getting-started`.
+fn slugify(title: &str) -> String {
+	let mut slug = String::with_capacity(title.len());
+	for c in title.chars().flat_map(char::to_lowercase) {
+		if c.is_alphanumeric() || c == '_' {
+			slug.push(c);
+		} else if (c.is_whitespace() || c == '-') && !slug.is_empty() && !slug.ends_with('-') {
+			slug.push('-');
+		}
+	}
+	if slug.ends_with('-') {
+		slug.pop();
+	}
+	if slug.is_empty() {
+		slug.push_str("section");
+	}
+	slug
+}
+
+/// `slug`, or `slug-1`, `slug-2`, … when it is already taken.
+fn unique_id(slug: &str, used_ids: &mut HashSet<String>) -> String {
+	let mut id = slug.to_string();
+	let mut suffix = 0;
+	while used_ids.contains(&id) {
+		suffix += 1;
+		id = format!("{slug}-{suffix}");
+	}
+	used_ids.insert(id.clone());
+	id
+}
+
+/// Nested `<ul>` lists linking to every heading. A heading more than one
+/// level deeper than the one before it is nested a single level.
+fn table_of_contents(headings: &[Heading]) -> String {
+	let Some(base) = headings.iter().map(|heading| heading.level).min() else {
+		return String::new();
+	};
+
+	let mut toc = String::new();
+	let mut depth = 0;
+	for heading in headings {
+		let level = usize::from(heading.level - base + 1).min(depth + 1);
+		if level > depth {
+			toc.push_str("<ul>");
+			depth += 1;
+		} else {
+			toc.push_str("</li>");
+			while depth > level {
+				toc.push_str("</ul></li>");
+				depth -= 1;
+			}
+		}
+		toc.push_str("<li><a href=\"#");
+		escape_into(&mut toc, &heading.id);
+		toc.push_str("\">");
+		escape_into(&mut toc, &heading.title);
+		toc.push_str("</a>");
+	}
+	if depth > 0 {
+		toc.push_str("</li>");
+		for _ in 1..depth {
+			toc.push_str("</ul></li>");
+		}
+		toc.push_str("</ul>");
+	}
+	toc
+}
+
+fn escape_into(output: &mut String, input: &str) {
+	for c in input.chars() {
+		match c {
+			'&' => output.push_str("&amp;"),
+			'<' => output.push_str("&lt;"),
+			'>' => output.push_str("&gt;"),
+			'"' => output.push_str("&quot;"),
+			'\'' => output.push_str("&#x27;"),
+			_ => output.push(c),
+		}
+	}
+}
@@ -1,4 +1,6 @@
-use crate::tokens::{Component, Content};
+use crate::scoped_css::scope_view;
+use crate::tokens::{Component, Content, ControlTag};
+use proc_macro2::{Delimiter, Group, Ident};
 use syn::parse::{Parse, ParseStream};
 
 impl Parse for Component {
@@ -6,6 +8,7 @@ impl Parse for Component {
 		if input.is_empty() {
 			return Ok(Component {
 				children: Vec::new(),
+				scopes: Vec::new(),
 			});
 		}
 
@@ -16,6 +19,80 @@ impl Parse for Component {
 			children.push(child);
 		}
 
-		Ok(Component { children })
+		resolve_snippet_calls(&mut children, &mut Vec::new());
+		let scopes = scope_view(&mut children)?;
+
+		Ok(Component { children, scopes })
 	}
 }
+
+/// `@render{name(args)}` calls the snippet `name` if a `{#snippet name(…)}`
+/// before it, in the same or an enclosing block, declares one. Otherwise it's
+/// a function call, and the component it returns is rendered.
+///
+/// Blocks are those of the generated code: the branches of control tags and
+/// the children of components are, those of HTML elements aren't.
+fn resolve_snippet_calls(contents: &mut [Content], snippets: &mut Vec<Ident>) {
+	for content in contents.iter_mut() {
+		match content {
+			Content::Tag(tag) if tag.is_component() => in_block(&mut tag.children, snippets),
+			Content::Tag(tag) => resolve_snippet_calls(&mut tag.children, snippets),
+			Content::ControlTag(control_tag) => match control_tag {
+				ControlTag::IfChain {
+					if_content,
+					if_else_chain,
+					else_content,
+					..
+				} => {
+					in_block(if_content, snippets);
+					for (_, contents) in if_else_chain {
+						in_block(contents, snippets);
+					}
+					if let Some(contents) = else_content {
+						in_block(contents, snippets);
+					}
+				}
+				ControlTag::For {
+					contents,
+					empty_contents,
+					..
+				} => {
+					in_block(contents, snippets);
+					if let Some(contents) = empty_contents {
+						in_block(contents, snippets);
+					}
+				}
+				ControlTag::Match { cases, .. } => {
+					for (_, contents) in cases {
+						in_block(contents, snippets);
+					}
+				}
+				ControlTag::Slot { contents, .. } => in_block(contents, snippets),
+				ControlTag::Snippet { name, contents, .. } => {
+					in_block(contents, snippets);
+					snippets.push(name.clone());
+				}
+				ControlTag::Let { .. } => {}
+			},
+			Content::SlotRender {
+				fallback: Some(fallback),
+				..
+			} => in_block(fallback, snippets),
+			Content::SnippetRender { callee, args } if !snippets.contains(callee) => {
+				let mut arguments = Group::new(Delimiter::Parenthesis, args.clone());
+				arguments.set_span(callee.span());
+				*content = Content::ComponentRender {
+					component: vec![callee.clone().into(), arguments.into()],
+				};
+			}
+			_ => {}
+		}
+	}
+}
+
+/// Snippets declared in a block go out of scope at its end.
+fn in_block(contents: &mut [Content], snippets: &mut Vec<Ident>) {
+	let in_scope = snippets.len();
+	resolve_snippet_calls(contents, snippets);
+	snippets.truncate(in_scope);
+}
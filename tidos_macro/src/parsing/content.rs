@@ -1,14 +1,22 @@
+use crate::parsing::utils::{
+	is_cursor_on_branch_or_closing_tag, is_cursor_on_else_branch, matches_corresponding_command_tag,
+};
 use crate::tokens::{Content, ControlTag};
 use crate::tokens::{HTMLTag, TextContent};
-use proc_macro2::{Delimiter, Ident, Literal, Punct, Spacing, TokenTree};
+use proc_macro2::{Delimiter, Group, Ident, Literal, Punct, Spacing, TokenTree};
+use syn::parse::discouraged::Speculative;
 use syn::parse::{Parse, ParseStream};
 use syn::token::{Brace, Token};
 use syn::Token;
 
 const COMMAND_PREFIX: char = '#';
+const STATEMENT_PREFIX: char = '@';
 const RAW_HTML_PREFIX: char = '@';
 const RAW_HTML_IDENTIFIER: &str = "html";
+const SANITIZED_HTML_IDENTIFIER: &str = "sanitized_html";
+const MARKDOWN_IDENTIFIER: &str = "markdown";
 const SLOT_IDENTIFIER: &str = "slot";
+const RENDER_IDENTIFIER: &str = "render";
 
 impl Parse for Content {
 	fn parse(input: ParseStream) -> syn::Result<Self> {
@@ -23,23 +31,32 @@ impl Parse for Content {
 		} else if matches! { input.cursor().punct(), Some((punct, _)) if punct.as_char() == RAW_HTML_PREFIX}
 		{
 			// @html{"<p>Hello world</p>"} @html{"<p>Hello {}</p>", name} @html{ markdown }
+			// @sanitized_html{comment.body} @markdown{post.body}
 			// @slot{self.body}
+			// @render{row(&user)}
 
 			Self::parse_at_statement(input)
-		} else if input.peek(Brace) && is_cursor_at_command(input.cursor()) {
+		} else if input.peek(Brace)
+			&& (is_cursor_at_command(input.cursor()) || is_cursor_at_statement(input.cursor()))
+		{
 			// {#for ... in ... } {#if} {#match}
+			// {@let x = ...}
 
 			Ok(Content::ControlTag(ControlTag::parse(input)?))
-		} else if input.peek(Brace) && !is_cursor_at_command(input.cursor()) {
+		} else if input.peek(Brace) {
 			// {"Hello world"} {"Hello {}", name} { name }
 			let text_content = Self::parse_text_content(input)?;
 			Ok(Content::Text(text_content))
 		} else {
 			// text between tags
-			if let Ok(x) = Self::parse_text_between_tags(input) {
-				panic!("Raw text between tags is no longer supported. Use `{{\"{x}\"}}` instead.")
-			}
-			unreachable!();
+			let span = input.span();
+			let text = Self::parse_text_between_tags(input)?;
+			Err(syn::Error::new(
+				span,
+				format!(
+					"Raw text between tags is no longer supported. Use `{{\"{text}\"}}` instead."
+				),
+			))
 		}
 	}
 }
@@ -119,31 +136,120 @@ impl Content {
 				let text_content = Self::parse_text_content(input)?;
 				Ok(Content::RawHTMLExpression(text_content))
 			}
+			SANITIZED_HTML_IDENTIFIER => {
+				let text_content = Self::parse_text_content(input)?;
+				Ok(Content::SanitizedHTMLExpression(text_content))
+			}
+			MARKDOWN_IDENTIFIER => {
+				let text_content = Self::parse_text_content(input)?;
+				Ok(Content::MarkdownExpression(text_content))
+			}
 			SLOT_IDENTIFIER => {
-				let expr = Self::parse_slot_expr(input)?;
-				Ok(Content::SlotRender(expr))
+				let (slot, args) = Self::parse_slot_expr(input)?;
+				let fallback = Self::parse_slot_fallback(input, ident.span())?;
+				Ok(Content::SlotRender {
+					slot,
+					args,
+					fallback,
+				})
 			}
+			RENDER_IDENTIFIER => Self::parse_render(input),
 			other => Err(syn::Error::new(
 				ident.span(),
 				format!(
-					"Did you mean `{RAW_HTML_IDENTIFIER}` or `{SLOT_IDENTIFIER}`? Got `{other}`"
+					"Did you mean `{RAW_HTML_IDENTIFIER}`, `{SANITIZED_HTML_IDENTIFIER}`, `{MARKDOWN_IDENTIFIER}`, `{SLOT_IDENTIFIER}` or `{RENDER_IDENTIFIER}`? Got `{other}`"
 				),
 			)),
 		}
 	}
 
-	fn parse_slot_expr(input: ParseStream) -> syn::Result<Vec<TokenTree>> {
+	/// Parses `{self.slot}` or, for a scoped slot, `{self.slot, argument}`.
+	fn parse_slot_expr(input: ParseStream) -> syn::Result<(Vec<TokenTree>, Vec<TokenTree>)> {
 		let content;
 		syn::braced!(content in input);
+		let mut slot = vec![];
+		while !content.is_empty() && !content.peek(Token![,]) {
+			slot.push(content.parse::<TokenTree>()?);
+		}
+		let mut args = vec![];
+		if content.parse::<Token![,]>().is_ok() {
+			while let Ok(token) = content.parse::<TokenTree>() {
+				args.push(token);
+			}
+		}
+		Ok((slot, args))
+	}
+
+	/// Parses the optional `{:else} ... {/slot}` fallback of an `@slot{...}`.
+	///
+	/// Inside an `{#if}`, the `{:else}` after an `@slot{...}` may be the if's
+	/// next branch instead. The branch is parsed like any other, up to the next
+	/// branch or closing tag on its level, and belongs to the slot when that tag
+	/// is `{/slot}`.
+	fn parse_slot_fallback(
+		input: ParseStream,
+		slot_span: proc_macro2::Span,
+	) -> syn::Result<Option<Vec<Content>>> {
+		if !is_cursor_on_else_branch(&input.cursor()) {
+			return Ok(None);
+		}
+
+		let branch = input.fork();
+		branch.parse::<Group>()?;
+		let fallback =
+			ControlTag::parse_content_until(&&branch, slot_span, SLOT_IDENTIFIER, |cursor| {
+				is_cursor_on_branch_or_closing_tag(&cursor)
+			})?;
+		if !matches_corresponding_command_tag(branch.cursor(), SLOT_IDENTIFIER) {
+			return Ok(None);
+		}
+
+		// {/slot}
+		branch.parse::<Group>()?;
+		input.advance_to(&branch);
+
+		Ok(Some(fallback))
+	}
+
+	/// `@render{row(&user)}` might call a snippet, anything else, like
+	/// `@render{self.widgets}`, renders a value implementing `Component`.
+	/// Whether `row` is a snippet is only known once the whole view is
+	/// parsed, see `resolve_snippet_calls`.
+	fn parse_render(input: ParseStream) -> syn::Result<Self> {
+		let content;
+		let braces = syn::braced!(content in input);
 		let mut tokens = vec![];
 		while let Ok(token) = content.parse::<TokenTree>() {
 			tokens.push(token);
 		}
-		Ok(tokens)
+
+		match tokens.as_slice() {
+			[] => Err(syn::Error::new(
+				braces.span.join(),
+				"expected a snippet call like `@render{row(&user)}` or a component like `@render{self.sidebar}`",
+			)),
+			[TokenTree::Ident(callee), TokenTree::Group(arguments)]
+				if arguments.delimiter() == Delimiter::Parenthesis =>
+			{
+				Ok(Content::SnippetRender {
+					callee: callee.clone(),
+					args: arguments.stream(),
+				})
+			}
+			_ => Ok(Content::ComponentRender { component: tokens }),
+		}
 	}
 }
 
 fn is_cursor_at_command(cursor: syn::buffer::Cursor) -> bool {
+	is_cursor_at_group_with_prefix(cursor, COMMAND_PREFIX)
+}
+
+fn is_cursor_at_statement(cursor: syn::buffer::Cursor) -> bool {
+	is_cursor_at_group_with_prefix(cursor, STATEMENT_PREFIX)
+}
+
+fn is_cursor_at_group_with_prefix(cursor: syn::buffer::Cursor, prefix: char) -> bool {
 	// First check if we're at a brace
 	if cursor.group(Delimiter::Brace).is_none() {
 		return false;
@@ -154,9 +260,9 @@ fn is_cursor_at_command(cursor: syn::buffer::Cursor) -> bool {
 		// Look at the first token inside the group
 		let first_cursor = inside_cursor;
 
-		// Check if first token is a # punctuation
+		// Check if first token is the expected punctuation
 		if let Some((punct, _)) = first_cursor.punct() {
-			return punct.as_char() == COMMAND_PREFIX;
+			return punct.as_char() == prefix;
 		}
 	}
 
@@ -1,18 +1,20 @@
 use crate::parsing::utils::{
-	is_cursor_on_else_branch, is_cursor_on_else_if_branch, is_cursor_on_end_of_if_branch,
-	is_cursor_on_new_if_branch, matches_case_statement, matches_corresponding_command_tag,
+	is_cursor_on_else_branch, is_cursor_on_else_if_branch, is_cursor_on_empty_branch,
+	is_cursor_on_end_of_if_branch, is_cursor_on_new_if_branch, matches_case_statement,
+	matches_corresponding_command_tag,
 };
 use crate::tokens::{Content, ControlTag, TypeOfCommandTag};
-use proc_macro2::{Group, Ident, TokenTree};
+use proc_macro2::{Delimiter, Group, Ident, Spacing, TokenStream, TokenTree};
 use syn::parse::{Parse, ParseStream};
 use syn::spanned::Spanned;
-use syn::token::Pound;
+use syn::token::{At, Pound};
 use syn::Token;
 
 const LOOP_TAG: &str = "for";
 const CONDITIONAL_TAG: &str = "if";
 const MATCH_TAG: &str = "match";
 const SLOT_TAG: &str = "slot";
+const SNIPPET_TAG: &str = "snippet";
 
 impl Parse for ControlTag {
 	fn parse(input: ParseStream) -> syn::Result<Self> {
@@ -23,14 +25,21 @@ impl Parse for ControlTag {
 			TypeOfCommandTag::For {
 				left_side,
 				right_side,
-			} => Self::parse_for_loop_body(input, &group, left_side, right_side),
+				loop_binding,
+			} => Self::parse_for_loop_body(input, &group, left_side, right_side, loop_binding),
 			TypeOfCommandTag::If(if_statement) => {
 				Self::parse_if_statement_body(&input, &group, if_statement)
 			}
 			TypeOfCommandTag::Match(match_statement) => {
 				Self::parse_match_body(input, group, match_statement)
 			}
-			TypeOfCommandTag::Slot(name) => Self::parse_slot_body(input, &group, name),
+			TypeOfCommandTag::Slot { name, params } => {
+				Self::parse_slot_body(input, &group, name, params)
+			}
+			TypeOfCommandTag::Snippet { name, params } => {
+				Self::parse_snippet_body(input, &group, name, params)
+			}
+			TypeOfCommandTag::Let { pattern, value } => Ok(ControlTag::Let { pattern, value }),
 		}?;
 
 		Ok(control_tag)
@@ -43,17 +52,43 @@ impl ControlTag {
 		group: &Group,
 		left_side: Vec<TokenTree>,
 		right_side: Vec<TokenTree>,
+		loop_binding: Option<Ident>,
 	) -> syn::Result<Self> {
 		// ...
 		let contents = Self::parse_content_until(&input, group.span(), LOOP_TAG, |cursor| {
 			matches_corresponding_command_tag(cursor, LOOP_TAG)
+				|| is_cursor_on_empty_branch(&cursor)
 		})?;
 
+		// {:empty} ...
+		let empty_contents = if is_cursor_on_empty_branch(&input.cursor()) {
+			input.parse::<Group>()?;
+
+			let contents = Self::parse_content_until(&input, group.span(), LOOP_TAG, |cursor| {
+				matches_corresponding_command_tag(cursor, LOOP_TAG)
+					|| is_cursor_on_empty_branch(&cursor)
+			})?;
+
+			if is_cursor_on_empty_branch(&input.cursor()) {
+				let duplicate = input.parse::<Group>()?;
+				return Err(syn::Error::new(
+					duplicate.span(),
+					"cannot have two {:empty} blocks in one {#for}",
+				));
+			}
+
+			Some(contents)
+		} else {
+			None
+		};
+
 		// {/for}
 		Self::parse_closing_tag(input, group.span(), LOOP_TAG, || ControlTag::For {
 			left_side,
 			right_side,
+			loop_binding,
 			contents,
+			empty_contents,
 		})
 	}
 
@@ -151,7 +186,12 @@ impl ControlTag {
 		})
 	}
 
-	fn parse_slot_body(input: ParseStream, group: &Group, name: Ident) -> syn::Result<Self> {
+	fn parse_slot_body(
+		input: ParseStream,
+		group: &Group,
+		name: Ident,
+		params: Option<TokenStream>,
+	) -> syn::Result<Self> {
 		// ...
 		let contents = Self::parse_content_until(&input, group.span(), SLOT_TAG, |cursor| {
 			matches_corresponding_command_tag(cursor, SLOT_TAG)
@@ -160,6 +200,26 @@ impl ControlTag {
 		// {/for}
 		Self::parse_closing_tag(input, group.span(), SLOT_TAG, || ControlTag::Slot {
 			name,
+			params,
+			contents,
+		})
+	}
+
+	fn parse_snippet_body(
+		input: ParseStream,
+		group: &Group,
+		name: Ident,
+		params: TokenStream,
+	) -> syn::Result<Self> {
+		// ...
+		let contents = Self::parse_content_until(&input, group.span(), SNIPPET_TAG, |cursor| {
+			matches_corresponding_command_tag(cursor, SNIPPET_TAG)
+		})?;
+
+		// {/snippet}
+		Self::parse_closing_tag(input, group.span(), SNIPPET_TAG, || ControlTag::Snippet {
+			name,
+			params,
 			contents,
 		})
 	}
@@ -195,7 +255,7 @@ impl ControlTag {
 		}
 	}
 
-	fn parse_content_until<F>(
+	pub(crate) fn parse_content_until<F>(
 		input: &ParseStream,
 		group_span: proc_macro2::Span,
 		tag_name: &str,
@@ -221,6 +281,11 @@ impl ControlTag {
 
 impl Parse for TypeOfCommandTag {
 	fn parse(input: ParseStream) -> syn::Result<Self> {
+		if input.peek(Token![@]) {
+			let statement_token = input.parse::<Token![@]>()?;
+			return Self::parse_statement(input, statement_token);
+		}
+
 		let command_token = input.parse::<Token![#]>()?;
 
 		if input.peek(Token![for]) {
@@ -230,11 +295,11 @@ impl Parse for TypeOfCommandTag {
 		} else if input.peek(Token![if]) {
 			Self::parse_conditional_statements(input, command_token)
 		} else if input.peek(syn::Ident) {
-			Self::parse_slot(input, command_token)
+			Self::parse_named_tag(input, command_token)
 		} else {
 			Err(syn::Error::new(
 				command_token.span(),
-				"Unknown command tag, must be: 'for', 'if', 'match' or 'slot'",
+				"Unknown command tag, must be: 'for', 'if', 'match', 'slot' or 'snippet'",
 			))
 		}
 	}
@@ -276,6 +341,9 @@ impl TypeOfCommandTag {
 			let mut rest = *cursor;
 			let mut output = Vec::new();
 			while let Some((tt, next)) = rest.token_tree() {
+				if matches!(&tt, TokenTree::Punct(p) if p.as_char() == ';') {
+					break;
+				}
 				output.push(tt);
 				rest = next;
 			}
@@ -290,9 +358,38 @@ impl TypeOfCommandTag {
 			}
 		})?;
 
+		// ; loop
+		let loop_binding = if input.peek(Token![;]) {
+			let semicolon = input.parse::<Token![;]>()?;
+			let loop_binding = input.step(|cursor| {
+				cursor.ident().ok_or_else(|| {
+					syn::Error::new(
+						semicolon.span(),
+						"expected a single identifier after `;`, e.g. `{#for item in items; info}`",
+					)
+				})
+			})?;
+			if syn::parse_str::<Ident>(&loop_binding.to_string()).is_err() {
+				return Err(syn::Error::new(
+					loop_binding.span(),
+					format!("`{loop_binding}` is a reserved keyword, name the loop binding differently, e.g. `{{#for item in items; info}}`"),
+				));
+			}
+			if !input.is_empty() {
+				return Err(syn::Error::new(
+					input.span(),
+					"expected a single identifier after `;`, e.g. `{#for item in items; info}`",
+				));
+			}
+			Some(loop_binding)
+		} else {
+			None
+		};
+
 		Ok(TypeOfCommandTag::For {
 			left_side,
 			right_side,
+			loop_binding,
 		})
 	}
 
@@ -350,20 +447,147 @@ impl TypeOfCommandTag {
 		Ok(TypeOfCommandTag::If(if_content))
 	}
 
-	fn parse_slot(
+	fn parse_named_tag(
 		input: ParseStream,
 		command_token: Pound,
 	) -> Result<TypeOfCommandTag, syn::Error> {
-		let slot_ident = input.parse::<syn::Ident>()?;
-		if &slot_ident.to_string() == "slot" {
+		let tag_ident = input.parse::<syn::Ident>()?;
+		if tag_ident == SLOT_TAG {
 			input.parse::<Token![:]>()?;
-			let name_ident = input.parse::<syn::Ident>()?;
-			Ok(TypeOfCommandTag::Slot(name_ident))
+			let name = input.parse::<syn::Ident>()?;
+			let params = Self::parse_slot_params(input)?;
+			Ok(TypeOfCommandTag::Slot { name, params })
+		} else if tag_ident == SNIPPET_TAG {
+			Self::parse_snippet(input, command_token)
 		} else {
 			Err(syn::Error::new(
 				command_token.span(),
-				"Unknown command tag, must be: 'for', 'if', 'match' or 'slot'",
+				"Unknown command tag, must be: 'for', 'if', 'match', 'slot' or 'snippet'",
+			))
+		}
+	}
+
+	/// Parses the optional `|item|` parameter list of a scoped slot.
+	fn parse_slot_params(input: ParseStream) -> Result<Option<TokenStream>, syn::Error> {
+		if input.parse::<Token![||]>().is_ok() {
+			return Ok(None);
+		}
+
+		let Ok(opening_pipe) = input.parse::<Token![|]>() else {
+			return Ok(None);
+		};
+
+		let mut params = TokenStream::new();
+		while !input.peek(Token![|]) {
+			if input.is_empty() {
+				return Err(syn::Error::new(
+					opening_pipe.span(),
+					"missing closing `|` of the slot parameter list, e.g. `{#slot:row |item|}`",
+				));
+			}
+			params.extend([input.parse::<TokenTree>()?]);
+		}
+		input.parse::<Token![|]>()?;
+
+		if !input.is_empty() {
+			return Err(syn::Error::new(
+				input.span(),
+				"unexpected tokens after the slot parameter list",
+			));
+		}
+
+		Ok(Some(params))
+	}
+
+	fn parse_snippet(
+		input: ParseStream,
+		command_token: Pound,
+	) -> Result<TypeOfCommandTag, syn::Error> {
+		let Ok(name) = input.parse::<syn::Ident>() else {
+			return Err(syn::Error::new(
+				command_token.span(),
+				"expected a snippet name, e.g. `{#snippet row(user: &User)}`",
+			));
+		};
+
+		let params = match input.parse::<Group>() {
+			Ok(group) if group.delimiter() == Delimiter::Parenthesis => group.stream(),
+			_ => {
+				return Err(syn::Error::new(
+					name.span(),
+					format!("expected a parameter list after the snippet name, e.g. `{{#snippet {name}()}}`"),
+				))
+			}
+		};
+
+		if !input.is_empty() {
+			return Err(syn::Error::new(
+				input.span(),
+				"unexpected tokens after the snippet parameter list",
+			));
+		}
+
+		Ok(TypeOfCommandTag::Snippet { name, params })
+	}
+
+	fn parse_statement(
+		input: ParseStream,
+		statement_token: At,
+	) -> Result<TypeOfCommandTag, syn::Error> {
+		if !input.peek(Token![let]) {
+			return Err(syn::Error::new(
+				statement_token.span(),
+				"Unknown statement tag, must be: 'let'",
+			));
+		}
+		input.parse::<Token![let]>()?;
+
+		let pattern = input.step(|cursor| {
+			let mut rest = *cursor;
+			let mut output: Vec<TokenTree> = Vec::new();
+			while let Some((tt, next)) = rest.token_tree() {
+				// Stop at a lone `=`, but not at `==`, `<=`, `..=` and friends.
+				let is_lone_equals_sign = matches!(&tt, TokenTree::Punct(p) if p.as_char() == '=' && p.spacing() == Spacing::Alone);
+				let follows_joint_punct =
+					matches!(output.last(), Some(TokenTree::Punct(p)) if p.spacing() == Spacing::Joint);
+				if is_lone_equals_sign && !follows_joint_punct {
+					return Ok((output, next));
+				}
+				output.push(tt);
+				rest = next;
+			}
+
+			Err(syn::Error::new(
+				statement_token.span(),
+				"No `=` found in let binding, e.g. `{@let total = price * amount}`",
 			))
+		})?;
+
+		if pattern.is_empty() {
+			return Err(syn::Error::new(
+				statement_token.span(),
+				"expected a binding variable before `=`, e.g. `{@let total = price * amount}`",
+			));
 		}
+
+		let value = input.step(|cursor| {
+			let mut rest = *cursor;
+			let mut output = Vec::new();
+			while let Some((tt, next)) = rest.token_tree() {
+				output.push(tt);
+				rest = next;
+			}
+
+			if output.is_empty() {
+				Err(syn::Error::new(
+					statement_token.span(),
+					"Empty right side of `=`.",
+				))
+			} else {
+				Ok((output, rest))
+			}
+		})?;
+
+		Ok(TypeOfCommandTag::Let { pattern, value })
 	}
 }
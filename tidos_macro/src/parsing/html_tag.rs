@@ -1,17 +1,24 @@
-use crate::parsing::utils::{matches_tag, peek_closing_tag_name};
+use crate::parsing::utils::{matches_tag, normalize_tag_name, peek_closing_tag_name};
 use crate::tokens::{Attribute, AttributeType, Attributes, Content, HTMLTag};
 use proc_macro2::{Group, Literal};
+use quote::ToTokens;
 use syn::parse::{Parse, ParseStream};
 use syn::spanned::Spanned;
 use syn::token::Lt;
-use syn::Token;
+use syn::{Path, PathArguments, Token};
 
 impl Parse for HTMLTag {
 	fn parse(input: ParseStream) -> syn::Result<Self> {
 		// <p>
 		let start_first_tag_token = input.parse::<Token![<]>()?;
-		let (tag, tag_span) = Self::extract_name(input)?;
-		let is_component = tag.chars().next().unwrap().is_ascii_uppercase();
+		let is_component = Self::is_peeking_at_component(input);
+		let (tag, tag_span, component_path) = if is_component {
+			let (tag, path) = Self::extract_component_path(input)?;
+			(tag, path.segments[0].ident.span(), Some(path))
+		} else {
+			let (tag, tag_span) = Self::extract_name(input)?;
+			(tag, tag_span, None)
+		};
 		let attributes = Self::parse_attributes(input, &tag, start_first_tag_token.span())?;
 
 		if !is_component && attributes.has_default_flag {
@@ -29,7 +36,8 @@ impl Parse for HTMLTag {
 			return Ok(HTMLTag {
 				tag,
 				tag_span,
-				closing_tag_span: None,
+				component_path: component_path.map(ToTokens::into_token_stream),
+				closing_component_path: None,
 				attributes,
 				children: vec![],
 				is_self_closing: true,
@@ -50,13 +58,19 @@ impl Parse for HTMLTag {
 		// </p>
 		input.parse::<Token![<]>()?;
 		input.parse::<Token![/]>()?;
-		let (_, closing_span) = Self::extract_name(input)?;
+		let closing_component_path = if is_component {
+			Some(Self::extract_component_path(input)?.1)
+		} else {
+			Self::extract_name(input)?;
+			None
+		};
 		input.parse::<Token![>]>()?;
 
 		Ok(HTMLTag {
 			tag,
 			tag_span,
-			closing_tag_span: Some(closing_span),
+			component_path: component_path.map(ToTokens::into_token_stream),
+			closing_component_path: closing_component_path.map(ToTokens::into_token_stream),
 			attributes,
 			children,
 			is_self_closing: false,
@@ -96,6 +110,52 @@ impl HTMLTag {
 		})
 	}
 
+	/// Components start with an uppercase letter, like `Card` or `Self::Inner`,
+	/// or are a path, like `ui::Card` or `crate::ui::Card`.
+	fn is_peeking_at_component(input: ParseStream) -> bool {
+		let Some((ident, rest)) = input.cursor().ident() else {
+			return false;
+		};
+		let is_path = matches!(rest.punct(), Some((p, next)) if p.as_char() == ':'
+			&& matches!(next.punct(), Some((p, _)) if p.as_char() == ':'));
+
+		is_path
+			|| ident
+				.to_string()
+				.starts_with(|c: char| c.is_ascii_uppercase())
+	}
+
+	/// Parses a component's path, e.g. `ui::Card`, `List<User>` or
+	/// `List::<User>`, and returns it with turbofish generics so it can be used
+	/// in expressions, next to its name as written in `tag` form.
+	fn extract_component_path(input: ParseStream) -> Result<(String, Path), syn::Error> {
+		let mut path = input.parse::<Path>().map_err(|error| {
+			syn::Error::new(
+				error.span(),
+				"expected a component like `Card`, `ui::Card` or `List<User>`",
+			)
+		})?;
+		for segment in &mut path.segments {
+			match &mut segment.arguments {
+				PathArguments::None => {}
+				PathArguments::AngleBracketed(arguments) => {
+					arguments.colon2_token.get_or_insert_with(Default::default);
+				}
+				PathArguments::Parenthesized(arguments) => {
+					return Err(syn::Error::new_spanned(
+						arguments,
+						"component paths cannot have parenthesized arguments, use `<…>` for generics",
+					));
+				}
+			}
+		}
+
+		Ok((
+			normalize_tag_name(&path.to_token_stream().to_string()),
+			path,
+		))
+	}
+
 	fn is_peeking_at_self_closing_tag(input: ParseStream) -> bool {
 		input.peek(Token![/]) && input.peek2(Token![>])
 	}
@@ -25,6 +25,18 @@ pub fn matches_case_statement(cursor: Cursor) -> bool {
 	}
 }
 
+pub fn is_cursor_on_empty_branch(cursor: &Cursor) -> bool {
+	if let Some((a, _, _)) = cursor.group(Delimiter::Brace) {
+		let peeked: Vec<TokenTree> = a.token_stream().into_iter().take(3).collect();
+
+		peeked.len() == 2
+			&& matches!(&peeked[0], TokenTree::Punct(p) if p.as_char() == ':')
+			&& matches!(&peeked[1], TokenTree::Ident(i) if &i.to_string() == "empty")
+	} else {
+		false
+	}
+}
+
 pub fn is_cursor_on_new_if_branch(cursor: &Cursor) -> bool {
 	is_cursor_on_else_if_branch(cursor)
 		|| is_cursor_on_else_branch(cursor)
@@ -68,7 +80,17 @@ pub fn is_cursor_on_end_of_if_branch(cursor: &Cursor) -> bool {
 	}
 }
 
-pub fn peek_closing_tag_name(cursor: Cursor) -> Option<String> {
+/// Spells a tag name the same way whether it was written as `List<User>`,
+/// `List::<User>` or `List < User >`, so closing tags can be compared.
+pub fn normalize_tag_name(name: &str) -> String {
+	name.split_whitespace()
+		.collect::<String>()
+		.replace("::<", "<")
+}
+
+/// Reads the name of the closing tag at `cursor`, e.g. `div` for `</div>` or
+/// `List<User>` for `</List<User>>`, up to but not including the final `>`.
+fn closing_tag_name(cursor: Cursor) -> Option<(String, Cursor)> {
 	let mut rest = cursor;
 	let (p, next) = rest.punct()?;
 	if p.as_char() != '<' {
@@ -83,60 +105,52 @@ pub fn peek_closing_tag_name(cursor: Cursor) -> Option<String> {
 	let (ident, mut rest) = rest.ident()?;
 	let mut name = ident.to_string();
 
-	while let Some((p, next)) = rest.punct() {
-		if p.as_char() != '-' {
-			break;
-		}
-		if let Some((ident, next)) = next.ident() {
-			name.push('-');
-			name.push_str(&ident.to_string());
-			rest = next;
+	// Everything up to the `>` that closes the tag, skipping over the `>` of
+	// generics, like `</List<User>>`, and of `->`, like `</Map<fn() -> u8>>`.
+	let mut depth = 0usize;
+	let mut previous = None;
+	while let Some((token, next)) = rest.token_tree() {
+		if let TokenTree::Punct(p) = &token {
+			match p.as_char() {
+				'<' => depth += 1,
+				'>' if previous == Some('-') => {}
+				'>' if depth == 0 => break,
+				'>' => depth -= 1,
+				_ => {}
+			}
+			previous = Some(p.as_char());
 		} else {
-			break;
+			previous = None;
 		}
+		name.push_str(&token.to_string());
+		rest = next;
 	}
 
-	Some(name)
+	Some((normalize_tag_name(&name), rest))
 }
 
-pub fn matches_tag(cursor: Cursor, target_tag: &String) -> bool {
-	let mut rest = cursor;
-	if !matches!(rest.punct(), Some((punct, _)) if punct.as_char() == '<') {
-		return false;
-	}
-	let (_, next) = rest.punct().unwrap();
-	rest = next;
-
-	if !matches!(rest.punct(), Some((punct, _)) if punct.as_char() == '/') {
-		return false;
-	}
-	let (_, next) = rest.punct().unwrap();
-	rest = next;
+pub fn peek_closing_tag_name(cursor: Cursor) -> Option<String> {
+	closing_tag_name(cursor).map(|(name, _)| name)
+}
 
-	let mut right_hand_side = String::new();
-	let Some((ident, next)) = rest.ident() else {
+pub fn matches_tag(cursor: Cursor, target_tag: &String) -> bool {
+	let Some((name, rest)) = closing_tag_name(cursor) else {
 		return false;
 	};
-	right_hand_side.push_str(ident.to_string().as_str());
-
-	rest = next;
-
-	while matches!(rest.punct(), Some((p, _)) if p.as_char() == '-') {
-		let (_, next) = rest.punct().unwrap();
-		rest = next;
 
-		right_hand_side.push('-');
-		let Some((ident, next)) = rest.ident() else {
-			return false;
-		};
-
-		right_hand_side.push_str(ident.to_string().as_str());
-		rest = next;
-	}
+	name == *target_tag && matches!(rest.punct(), Some((punct, _)) if punct.as_char() == '>')
+}
 
-	if right_hand_side != *target_tag {
+/// Checks whether the cursor is on a tag that ends the current branch of a
+/// block: the next branch, e.g. `{:else}` or `{:case …}`, or a closing tag like
+/// `{/if}`.
+pub fn is_cursor_on_branch_or_closing_tag(cursor: &Cursor) -> bool {
+	let Some((inside, _, _)) = cursor.group(Delimiter::Brace) else {
 		return false;
-	}
+	};
+	let peeked: Vec<TokenTree> = inside.token_stream().into_iter().take(2).collect();
 
-	matches!(rest.punct(), Some((punct, _)) if punct.as_char() == '>')
+	peeked.len() == 2
+		&& matches!(&peeked[0], TokenTree::Punct(p) if matches!(p.as_char(), ':' | '/'))
+		&& matches!(&peeked[1], TokenTree::Ident(_))
 }
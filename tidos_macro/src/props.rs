@@ -0,0 +1,444 @@
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote, ToTokens, TokenStreamExt};
+use syn::parse::{Parse, ParseStream};
+use syn::{
+	parse_quote, Data, DeriveInput, Expr, Fields, GenericParam, Generics, Ident, Member, Type,
+	Visibility,
+};
+
+/// A struct deriving `Props`, expanded into a typestate builder that
+/// `view!` uses to construct the component.
+pub struct PropsDerive {
+	vis: Visibility,
+	name: Ident,
+	generics: Generics,
+	props: Vec<Prop>,
+}
+
+struct Prop {
+	member: Member,
+	setter: Ident,
+	ty: Type,
+	/// `#[prop(into)]`, the setter accepts any `impl Into<T>`.
+	into: bool,
+	value: PropValue,
+	default: Option<PropDefault>,
+}
+
+/// What the setter used by `view!` accepts, see [`prop_value`].
+#[derive(Clone, Copy)]
+enum PropValue {
+	/// `impl tidos::IntoProp<T>`.
+	Converted,
+	/// `T` itself.
+	Exact,
+	/// `T` or `Option<T>` for an `Option<T>` field, e.g. `Option<u64>`.
+	Optional,
+}
+
+enum PropDefault {
+	/// `#[prop(optional)]` or an `Option<T>` field.
+	Trait,
+	/// `#[prop(default = …)]`.
+	Expr(Box<Expr>),
+}
+
+impl Parse for PropsDerive {
+	fn parse(input: ParseStream) -> syn::Result<Self> {
+		let input = DeriveInput::parse(input)?;
+
+		let fields = match input.data {
+			Data::Struct(data) => data.fields,
+			_ => {
+				return Err(syn::Error::new(
+					input.ident.span(),
+					"#[derive(Props)] only supports structs",
+				))
+			}
+		};
+
+		let mut props = Vec::with_capacity(fields.len());
+		let named = matches!(fields, Fields::Named(_));
+		for (index, field) in fields.into_iter().enumerate() {
+			let (member, setter) = match field.ident {
+				Some(ident) => (Member::Named(ident.clone()), ident),
+				None => (
+					Member::Unnamed(index.into()),
+					format_ident!("_{}", index, span = Span::call_site()),
+				),
+			};
+			if named && setter == "build" {
+				return Err(syn::Error::new(
+					setter.span(),
+					"`build` is reserved for finishing the props builder, rename the field",
+				));
+			}
+
+			let mut prop = Prop {
+				member,
+				setter,
+				into: false,
+				value: prop_value(&field.ty, &input.generics),
+				default: is_option(&field.ty).then_some(PropDefault::Trait),
+				ty: field.ty,
+			};
+
+			for attr in field
+				.attrs
+				.iter()
+				.filter(|attr| attr.path().is_ident("prop"))
+			{
+				attr.parse_nested_meta(|meta| {
+					if meta.path.is_ident("into") {
+						prop.into = true;
+						Ok(())
+					} else if meta.path.is_ident("optional") {
+						prop.default = Some(PropDefault::Trait);
+						Ok(())
+					} else if meta.path.is_ident("default") {
+						prop.default = Some(PropDefault::Expr(meta.value()?.parse()?));
+						Ok(())
+					} else {
+						Err(meta.error(
+							"unknown prop option, must be: `into`, `optional` or `default = …`",
+						))
+					}
+				})?;
+			}
+
+			props.push(prop);
+		}
+
+		Ok(PropsDerive {
+			vis: input.vis,
+			name: input.ident,
+			generics: input.generics,
+			props,
+		})
+	}
+}
+
+impl ToTokens for PropsDerive {
+	fn to_tokens(&self, tokens: &mut TokenStream) {
+		let PropsDerive {
+			vis,
+			name,
+			generics,
+			props,
+		} = self;
+		let builder = format_ident!("{}Builder", name);
+		let builder_doc = format!(
+			"Builder for [`{}`], created by [`tidos::Props::builder`].",
+			name
+		);
+
+		let struct_args = generic_arguments(generics);
+		let state_params = (0..props.len())
+			.map(|index| format_ident!("__P{}", index))
+			.collect::<Vec<_>>();
+		let unset_state = props.iter().map(|_| quote!(())).collect::<Vec<_>>();
+		let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+		let mut builder_generics = generics.clone();
+		builder_generics.params.push(parse_quote!(__TidosProps));
+		let (_, _, builder_where) = builder_generics.split_for_impl();
+		let builder_params = &builder_generics.params;
+
+		tokens.append_all(quote! {
+			#[doc = #builder_doc]
+			#[must_use]
+			#vis struct #builder <#builder_params> #builder_where {
+				props: __TidosProps,
+				component: ::core::marker::PhantomData<fn() -> #name #ty_generics>,
+			}
+
+			impl #impl_generics tidos::Props for #name #ty_generics #where_clause {
+				type Builder = #builder <#( #struct_args, )* ( #( #unset_state, )* )>;
+
+				fn builder() -> Self::Builder {
+					#builder {
+						props: ( #( #unset_state, )* ),
+						component: ::core::marker::PhantomData,
+					}
+				}
+			}
+		});
+
+		// One setter per prop, only callable while that prop is still unset.
+		for (index, prop) in props.iter().enumerate() {
+			let Prop {
+				setter,
+				ty,
+				into,
+				value,
+				..
+			} = prop;
+			let other_params = state_params
+				.iter()
+				.enumerate()
+				.filter(|(other, _)| *other != index)
+				.map(|(_, param)| param);
+			let before = state_params.iter().enumerate().map(|(other, param)| {
+				if other == index {
+					quote!(())
+				} else {
+					param.to_token_stream()
+				}
+			});
+			let after = state_params
+				.iter()
+				.enumerate()
+				.map(|(other, param)| {
+					if other == index {
+						quote!((#ty,))
+					} else {
+						param.to_token_stream()
+					}
+				})
+				.collect::<Vec<_>>();
+			let bindings = state_params
+				.iter()
+				.enumerate()
+				.map(|(other, param)| {
+					if other == index {
+						quote!(())
+					} else {
+						lowercase(param).to_token_stream()
+					}
+				})
+				.collect::<Vec<_>>();
+			let values = state_params.iter().enumerate().map(|(other, param)| {
+				if other == index {
+					quote!((value,))
+				} else {
+					lowercase(param).to_token_stream()
+				}
+			});
+			let (value_ty, conversion) = if *into {
+				(
+					quote!(impl ::core::convert::Into<#ty>),
+					quote!(let value = ::core::convert::Into::into(value);),
+				)
+			} else {
+				(ty.to_token_stream(), quote!())
+			};
+			// The setter `view!` calls, converting the attribute value first.
+			let view_setter = format_ident!("__prop_{}", setter);
+			let (view_value_ty, view_conversion) = match value {
+				PropValue::Converted => (
+					quote!(impl tidos::IntoProp<#ty>),
+					quote!(tidos::IntoProp::<#ty>::into_prop(value)),
+				),
+				PropValue::Exact => (ty.to_token_stream(), quote!(value)),
+				PropValue::Optional => (
+					quote!(impl tidos::internals::IntoOptionProp<#ty>),
+					quote!(tidos::internals::IntoOptionProp::<#ty>::into_option_prop(value)),
+				),
+			};
+
+			let mut setter_generics = generics.clone();
+			setter_generics
+				.params
+				.extend(other_params.map(|param| -> GenericParam { parse_quote!(#param) }));
+			let (setter_impl_generics, _, _) = setter_generics.split_for_impl();
+
+			tokens.append_all(quote! {
+				impl #setter_impl_generics #builder <#( #struct_args, )* ( #( #before, )* )> #where_clause {
+					#vis fn #setter(self, value: #value_ty) -> #builder <#( #struct_args, )* ( #( #after, )* )> {
+						#conversion
+						let ( #( #bindings, )* ) = self.props;
+						#builder {
+							props: ( #( #values, )* ),
+							component: ::core::marker::PhantomData,
+						}
+					}
+
+					#[doc(hidden)]
+					#[inline]
+					#[allow(non_snake_case)]
+					#vis fn #view_setter(self, value: #view_value_ty) -> #builder <#( #struct_args, )* ( #( #after, )* )> {
+						self.#setter(#view_conversion)
+					}
+				}
+			});
+		}
+
+		// `build` exists for any combination of states, but its own where clause
+		// requires every required prop to be set. Bounds on the method rather
+		// than the impl are checked after method lookup, so the error reported is
+		// the `on_unimplemented` message of the trait naming the missing prop.
+		let mut build_generics = generics.clone();
+		build_generics.params.extend(
+			state_params
+				.iter()
+				.map(|param| -> GenericParam { parse_quote!(#param) }),
+		);
+		let mut state_bounds: Vec<syn::WherePredicate> = vec![];
+		let mut fields = vec![];
+		for (prop, param) in props.iter().zip(&state_params) {
+			let Prop {
+				member, setter, ty, ..
+			} = prop;
+			let value = lowercase(param);
+			match &prop.default {
+				None => {
+					let required = format_ident!("__{}RequiredProp_{}", name, setter);
+					let message = format!("missing required prop `{}` on `<{}>`", setter, name);
+					let label = format!("`<{}>` needs `{}={{{{…}}}}`", name, setter);
+					tokens.append_all(quote! {
+						#[doc(hidden)]
+						#[allow(non_camel_case_types)]
+						#[diagnostic::on_unimplemented(message = #message, label = #label)]
+						#vis trait #required<T> {
+							fn into_prop(self) -> T;
+						}
+
+						impl<T> #required<T> for (T,) {
+							#[inline]
+							fn into_prop(self) -> T {
+								self.0
+							}
+						}
+					});
+					state_bounds.push(parse_quote!(#param: #required<#ty>));
+					fields.push(quote!(#member: #required::into_prop(#value)));
+				}
+				Some(default) => {
+					let default = match default {
+						PropDefault::Trait => quote!(::core::default::Default::default),
+						PropDefault::Expr(expr) => quote!(|| #expr),
+					};
+					state_bounds.push(parse_quote!(#param: tidos::internals::OptionalProp<#ty>));
+					fields.push(quote! {
+						#member: tidos::internals::OptionalProp::into_prop_or_else(#value, #default)
+					});
+				}
+			}
+		}
+		let (build_impl_generics, _, _) = build_generics.split_for_impl();
+		let values = state_params.iter().map(lowercase);
+
+		tokens.append_all(quote! {
+			impl #build_impl_generics #builder <#( #struct_args, )* ( #( #state_params, )* )> #where_clause {
+				/// Finish the builder. Fails to compile while a required prop is unset.
+				#vis fn build(self) -> #name #ty_generics
+				where
+					#( #state_bounds, )*
+				{
+					let ( #( #values, )* ) = self.props;
+					#name {
+						#( #fields, )*
+					}
+				}
+			}
+		});
+	}
+}
+
+/// The generic arguments that name `generics`, e.g. `'a, T` for `<'a, T: Display>`.
+fn generic_arguments(generics: &Generics) -> Vec<TokenStream> {
+	generics
+		.params
+		.iter()
+		.map(|param| match param {
+			GenericParam::Lifetime(param) => param.lifetime.to_token_stream(),
+			GenericParam::Type(param) => param.ident.to_token_stream(),
+			GenericParam::Const(param) => param.ident.to_token_stream(),
+		})
+		.collect()
+}
+
+fn lowercase(param: &Ident) -> Ident {
+	format_ident!("{}", param.to_string().to_lowercase())
+}
+
+/// How `view!` passes a value to a prop of type `ty`.
+///
+/// Most values go through `tidos::IntoProp`, so `title="News"` fills a
+/// `String`. Values that need the field's exact type are passed as-is: a
+/// conversion would hide the coercions of references (`&Vec<T>` to `&[T]`) and
+/// boxes (a closure to a `Slot`), the inference of the struct's own type
+/// parameters and of `tidos::internals::slot`, and the type of number literals,
+/// which can't be inferred through `Into`.
+fn prop_value(ty: &Type, generics: &Generics) -> PropValue {
+	match ty {
+		Type::Reference(_) | Type::FnPtr(_) | Type::Ptr(_) | Type::TraitObject(_) => {
+			PropValue::Exact
+		}
+		Type::Paren(inner) => prop_value(&inner.elem, generics),
+		Type::Group(inner) => prop_value(&inner.elem, generics),
+		_ if mentions_type_param(ty, generics) => PropValue::Exact,
+		Type::Path(type_path) => match type_path.path.segments.last() {
+			Some(segment) if is_coerced(&segment.ident) || is_number(&segment.ident) => {
+				PropValue::Exact
+			}
+			Some(segment) if segment.ident == "Option" => match option_argument(segment) {
+				Some(inner) if is_coerced(inner) => PropValue::Exact,
+				Some(inner) if is_number(inner) => PropValue::Optional,
+				_ => PropValue::Converted,
+			},
+			_ => PropValue::Converted,
+		},
+		_ => PropValue::Converted,
+	}
+}
+
+/// The name of `T` in `Option<T>`.
+fn option_argument(segment: &syn::PathSegment) -> Option<&Ident> {
+	let syn::PathArguments::AngleBracketed(arguments) = &segment.arguments else {
+		return None;
+	};
+	match arguments.args.first()? {
+		syn::GenericArgument::Type(Type::Path(inner)) => {
+			inner.path.segments.last().map(|segment| &segment.ident)
+		}
+		_ => None,
+	}
+}
+
+fn is_number(ident: &Ident) -> bool {
+	[
+		"u8", "u16", "u32", "u64", "u128", "usize", "i8", "i16", "i32", "i64", "i128", "isize",
+		"f32", "f64",
+	]
+	.iter()
+	.any(|number| ident == number)
+}
+
+/// Smart pointers that values are coerced into, including tidos' slots.
+fn is_coerced(ident: &Ident) -> bool {
+	ident == "Box" || ident == "Rc" || ident == "Arc" || ident == "Slot" || ident == "SlotWith"
+}
+
+fn mentions_type_param(ty: &Type, generics: &Generics) -> bool {
+	let params = generics
+		.type_params()
+		.map(|param| param.ident.to_string())
+		.collect::<Vec<_>>();
+	if params.is_empty() {
+		return false;
+	}
+	ty.to_token_stream()
+		.into_iter()
+		.any(|token| mentions(&token, &params))
+}
+
+fn mentions(token: &proc_macro2::TokenTree, params: &[String]) -> bool {
+	match token {
+		proc_macro2::TokenTree::Ident(ident) => params.contains(&ident.to_string()),
+		proc_macro2::TokenTree::Group(group) => group
+			.stream()
+			.into_iter()
+			.any(|token| mentions(&token, params)),
+		_ => false,
+	}
+}
+
+fn is_option(ty: &Type) -> bool {
+	if let Type::Path(type_path) = ty {
+		if let Some(segment) = type_path.path.segments.last() {
+			return segment.ident == "Option";
+		}
+	}
+	false
+}
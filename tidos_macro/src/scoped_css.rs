@@ -0,0 +1,588 @@
+use crate::config::Config;
+use crate::css::{scope_stylesheet, validate};
+use crate::id::{call_site_file, crate_relative, module_name, stable_id};
+use crate::source_file::{track, SourceFile};
+use crate::tokens::{Attribute, AttributeType, Content, ControlTag, HTMLTag, TextContent};
+use proc_macro2::{Delimiter, Group, LineColumn, Literal, Span, TokenStream, TokenTree};
+use quote::{quote, quote_spanned, ToTokens, TokenStreamExt};
+use std::path::PathBuf;
+use syn::ext::IdentExt;
+use syn::parse::{Parse, ParseStream, Parser};
+use syn::{Expr, Ident, LitStr, Token};
+
+/// `scoped_css!("./card.css")`, a stylesheet scoped to the view it is used in,
+/// or inline as `scoped_css!{ .title { color: red } }` or
+/// `scoped_css!(r#".title { color: red }"#)`. `scoped_scss!("./card.scss")`
+/// compiles SCSS into one.
+#[derive(Debug)]
+pub struct ScopedCss {
+	pub id: String,
+	/// The files the stylesheet was read from, none for inline CSS.
+	files: Vec<PathBuf>,
+	css: String,
+	config: Config,
+	/// The macro's path as written in a view, e.g. `scoped_css` or
+	/// `tidos::scoped_css`, empty when the macro expands by itself.
+	path: TokenStream,
+	/// `progress = self.pct` after the stylesheet, set as `--progress` on the
+	/// element the class is assigned to.
+	properties: Vec<CustomProperty>,
+}
+
+#[derive(Debug)]
+struct CustomProperty {
+	/// The name without the leading `--`, with dashes for underscores.
+	name: String,
+	span: Span,
+	value: TokenStream,
+}
+
+impl Parse for ScopedCss {
+	fn parse(input: ParseStream) -> syn::Result<Self> {
+		if input.is_empty() {
+			return Err(input.error(
+				"expected the path to a stylesheet, e.g. `scoped_css!(\"./card.css\")`, or CSS",
+			));
+		}
+		if !input.peek(LitStr) {
+			let tokens = CssTokens::new(input.parse()?);
+			validate(&tokens.css)
+				.map_err(|error| syn::Error::new(tokens.span_at(error.offset), error.message))?;
+			return ScopedCss::inline(tokens.css);
+		}
+
+		let literal = input.parse::<LitStr>()?;
+		let properties = parse_properties(input)?;
+		let mut scoped_css = ScopedCss::from_literal(literal)?;
+		scoped_css.properties = properties;
+		Ok(scoped_css)
+	}
+}
+
+impl ScopedCss {
+	/// A stylesheet file, or inline CSS written as a string.
+	fn from_literal(literal: LitStr) -> syn::Result<ScopedCss> {
+		let value = literal.value();
+		// The literal names a file if there is one. Otherwise, a block or a
+		// statement such as `@import url(theme.css);` makes it the CSS itself.
+		let is_file = SourceFile::resolve(&literal).is_ok_and(|path| path.is_file());
+		if !is_file && (value.contains('{') || value.contains(';')) {
+			validate(&value).map_err(|error| {
+				let (line, column) = error.line_column(&value);
+				syn::Error::new(
+					literal.span(),
+					format!("{} at line {line}, column {}", error.message, column + 1),
+				)
+			})?;
+			return ScopedCss::inline(value);
+		}
+
+		let file = SourceFile::read(&literal)?;
+		validate(&file.contents).map_err(|error| {
+			let (line, column) = error.line_column(&file.contents);
+			file.error_at(&literal, &error.message, line, column)
+		})?;
+		// Named after the stylesheet, and hashed from its path and contents.
+		let id = stable_id(
+			file.path.file_stem().and_then(|stem| stem.to_str()),
+			&[
+				crate_relative(&file.path).as_bytes(),
+				file.contents.as_bytes(),
+			],
+		);
+		ScopedCss::new(id, vec![file.path], &file.contents)
+	}
+
+	/// Inline CSS is named after the module it's written in, and hashed from
+	/// that file's path and the CSS.
+	fn inline(source: String) -> syn::Result<ScopedCss> {
+		let file = call_site_file();
+		let id = stable_id(
+			file.as_deref().and_then(module_name),
+			&[
+				file.as_deref()
+					.map(crate_relative)
+					.unwrap_or_default()
+					.as_bytes(),
+				source.as_bytes(),
+			],
+		);
+		ScopedCss::new(id, Vec::new(), &source)
+	}
+
+	/// `scoped_scss!("./card.scss")`, named after the file like a stylesheet,
+	/// and hashed from its path and the compiled CSS, which includes the
+	/// partials it uses.
+	#[cfg(feature = "scss")]
+	pub fn parse_scss(input: ParseStream) -> syn::Result<ScopedCss> {
+		let literal = input.parse::<LitStr>().map_err(|error| {
+			syn::Error::new(
+				error.span(),
+				"expected the path to an SCSS file, e.g. `scoped_scss!(\"./card.scss\")`",
+			)
+		})?;
+		let properties = parse_properties(input)?;
+
+		let file = SourceFile::read(&literal)?;
+		let (css, files) = crate::scss::compile(&file, &literal)?;
+		let id = stable_id(
+			file.path.file_stem().and_then(|stem| stem.to_str()),
+			&[crate_relative(&file.path).as_bytes(), css.as_bytes()],
+		);
+		let mut scoped_css = ScopedCss::new(id, files, &css)?;
+		scoped_css.properties = properties;
+		Ok(scoped_css)
+	}
+
+	#[cfg(not(feature = "scss"))]
+	pub fn parse_scss(input: ParseStream) -> syn::Result<ScopedCss> {
+		Err(input.error("`scoped_scss!` requires the `scss` feature of tidos"))
+	}
+
+	fn new(id: String, files: Vec<PathBuf>, source: &str) -> syn::Result<ScopedCss> {
+		let config =
+			Config::read().map_err(|message| syn::Error::new(Span::call_site(), message))?;
+		let css = scope_stylesheet(source, &id, &config.targets);
+
+		Ok(ScopedCss {
+			id,
+			files,
+			css,
+			config,
+			path: TokenStream::new(),
+			properties: Vec::new(),
+		})
+	}
+
+	/// Statements that add the `<style>` to the page head, once per page.
+	/// With the `extract-css` feature the stylesheet is registered for
+	/// `tidos::styles` instead, and the head gets a `<link>` to it.
+	pub fn head_tokens(&self) -> TokenStream {
+		let track = self.files.iter().map(|file| track(file));
+		let track_config = self.config.track();
+		let id = &self.id;
+		let css = &self.css;
+		// `view!` expands the call itself, this keeps the macro's import used.
+		let path = &self.path;
+		let import = (!path.is_empty()).then(|| quote! { use #path as _; });
+		let add_to_head = if cfg!(feature = "extract-css") {
+			quote! {
+				tidos::internals::inventory::submit! {
+					tidos::internals::ScopedStyle { id: #id, css: #css }
+				}
+				page.add_elements_to_head("tidos-styles", String::from(tidos::styles::link()));
+			}
+		} else {
+			let style = format!("<style>{css}</style>");
+			quote! { page.add_elements_to_head(#id, String::from(#style)); }
+		};
+		quote! {
+			#( #track )*
+			#track_config
+			#import
+			#add_to_head
+		}
+	}
+}
+
+impl ToTokens for ScopedCss {
+	fn to_tokens(&self, tokens: &mut TokenStream) {
+		if let Some(property) = self.properties.first() {
+			// `view!` takes them out of the call it replaces.
+			tokens.append_all(
+				syn::Error::new(
+					property.span,
+					"custom properties are set on the element the class is assigned to, \
+					use `scoped_css!` in an attribute of an element in `view!`",
+				)
+				.to_compile_error(),
+			);
+			return;
+		}
+		if self.css.contains(&format!("[data-{}]", self.id)) {
+			// Only `view!` stamps elements with the scope's attribute.
+			tokens.append_all(
+				syn::Error::new(
+					Span::call_site(),
+					"outside of `view!` only `&` rules can match, as no element gets the \
+					scope's attribute; use `scoped_css!` in an attribute of an element in \
+					`view!`, or wrap the other selectors in `:global(…)`",
+				)
+				.to_compile_error(),
+			);
+			return;
+		}
+		let head = self.head_tokens();
+		let id = &self.id;
+		tokens.append_all(quote! {
+			{
+				#head
+				#id
+			}
+		});
+	}
+}
+
+/// Replaces the `scoped_css!` calls in a view's attributes by their class
+/// names, sets their custom properties in the `style` of the element a call is
+/// on, and stamps a scope's `data-<id>` attribute on that element and the
+/// native elements inside it, including the ones passed to slots. A view with
+/// a single stylesheet stamps every native element it writes. The elements
+/// rendered by child components aren't stamped, so the styles don't leak into
+/// them.
+pub fn scope_view(children: &mut [Content]) -> syn::Result<Vec<ScopedCss>> {
+	let mut scopes = Vec::<ScopedCss>::new();
+	for_each_tag(children, &mut |tag| {
+		let mut tag_scopes = Vec::new();
+		for attribute in &mut tag.attributes.attributes {
+			replace_in_attribute(attribute, &mut tag_scopes)?;
+		}
+		for mut scope in tag_scopes {
+			set_properties(tag, std::mem::take(&mut scope.properties))?;
+			let name = format!("data-{}", scope.id);
+			stamp(tag, &name);
+			for_each_tag(&mut tag.children, &mut |tag| {
+				stamp(tag, &name);
+				Ok(())
+			})?;
+			if scopes.iter().all(|other| other.id != scope.id) {
+				scopes.push(scope);
+			}
+		}
+		Ok(())
+	})?;
+
+	if let [scope] = scopes.as_slice() {
+		let name = format!("data-{}", scope.id);
+		for_each_tag(children, &mut |tag| {
+			stamp(tag, &name);
+			Ok(())
+		})?;
+	}
+
+	Ok(scopes)
+}
+
+/// Adds the valueless attribute `name` to a native element that doesn't have
+/// it yet.
+fn stamp(tag: &mut HTMLTag, name: &str) {
+	if tag.is_component()
+		|| tag
+			.attributes
+			.attributes
+			.iter()
+			.any(|attribute| attribute.name == name)
+	{
+		return;
+	}
+	tag.attributes.attributes.push(Attribute {
+		name: name.to_string(),
+		name_span: tag.tag_span,
+		attribute_type: AttributeType::Constant,
+	});
+}
+
+/// Prepends `--name:value;` for each property to the tag's `style`. The
+/// values are escaped by `tidos::internals::css_value` when the attribute is
+/// formatted.
+fn set_properties(tag: &mut HTMLTag, properties: Vec<CustomProperty>) -> syn::Result<()> {
+	let Some(span) = properties.first().map(|property| property.span) else {
+		return Ok(());
+	};
+	if tag.is_component() {
+		return Err(syn::Error::new(
+			span,
+			"custom properties can only be set on an HTML element, not a component",
+		));
+	}
+
+	let mut format = String::new();
+	let mut params = Vec::new();
+	for property in properties {
+		format.push_str(&format!("--{}:{{}};", property.name));
+		let value = property.value;
+		params.push(
+			quote_spanned! { property.span=> tidos::internals::css_value(&(#value)) }
+				.into_iter()
+				.collect(),
+		);
+	}
+
+	let attributes = &mut tag.attributes.attributes;
+	let Some(style) = attributes
+		.iter_mut()
+		.find(|attribute| attribute.name == "style")
+	else {
+		attributes.push(Attribute {
+			name: "style".to_string(),
+			name_span: span,
+			attribute_type: AttributeType::Expression {
+				content: TextContent::Formatted(Literal::string(&format), params),
+			},
+		});
+		return Ok(());
+	};
+
+	match &style.attribute_type {
+		AttributeType::ConstantLiteral { literal }
+		| AttributeType::Expression {
+			content: TextContent::Literal(literal),
+		} => {
+			let value = syn::parse2::<LitStr>(literal.to_token_stream())?.value();
+			format.push_str(&value.replace('{', "{{").replace('}', "}}"));
+		}
+		AttributeType::Expression {
+			content: TextContent::Formatted(literal, style_params),
+		} => {
+			format.push_str(&syn::parse2::<LitStr>(literal.to_token_stream())?.value());
+			params.extend(style_params.iter().cloned());
+		}
+		_ => {
+			return Err(syn::Error::new(
+				style.name_span,
+				"to add the custom properties of `scoped_css!`, `style` has to be a string \
+				or formatted, e.g. `style={\"width: {}px\", self.width}`",
+			));
+		}
+	}
+	style.attribute_type = AttributeType::Expression {
+		content: TextContent::Formatted(Literal::string(&format), params),
+	};
+	Ok(())
+}
+
+fn for_each_tag<F>(contents: &mut [Content], f: &mut F) -> syn::Result<()>
+where
+	F: FnMut(&mut HTMLTag) -> syn::Result<()>,
+{
+	for content in contents {
+		match content {
+			Content::Tag(tag) => {
+				f(tag)?;
+				for_each_tag(&mut tag.children, f)?;
+			}
+			Content::ControlTag(control_tag) => match control_tag {
+				ControlTag::IfChain {
+					if_content,
+					if_else_chain,
+					else_content,
+					..
+				} => {
+					for_each_tag(if_content, f)?;
+					for (_, contents) in if_else_chain {
+						for_each_tag(contents, f)?;
+					}
+					if let Some(contents) = else_content {
+						for_each_tag(contents, f)?;
+					}
+				}
+				ControlTag::For {
+					contents,
+					empty_contents,
+					..
+				} => {
+					for_each_tag(contents, f)?;
+					if let Some(contents) = empty_contents {
+						for_each_tag(contents, f)?;
+					}
+				}
+				ControlTag::Match { cases, .. } => {
+					for (_, contents) in cases {
+						for_each_tag(contents, f)?;
+					}
+				}
+				ControlTag::Slot { contents, .. } | ControlTag::Snippet { contents, .. } => {
+					for_each_tag(contents, f)?;
+				}
+				ControlTag::Let { .. } => {}
+			},
+			Content::SlotRender {
+				fallback: Some(fallback),
+				..
+			} => for_each_tag(fallback, f)?,
+			_ => {}
+		}
+	}
+	Ok(())
+}
+
+fn replace_in_attribute(attribute: &mut Attribute, scopes: &mut Vec<ScopedCss>) -> syn::Result<()> {
+	let AttributeType::Expression { content } = &mut attribute.attribute_type else {
+		return Ok(());
+	};
+	match content {
+		TextContent::Expression(expression) => {
+			if replace_calls(expression, scopes)? {
+				// `class={scoped_css!("./card.css")}` is a static attribute.
+				if let [TokenTree::Literal(literal)] = expression.as_slice() {
+					attribute.attribute_type = AttributeType::ConstantLiteral {
+						literal: literal.clone(),
+					};
+				}
+			}
+		}
+		TextContent::Formatted(_, params) => {
+			for param in params {
+				replace_calls(param, scopes)?;
+			}
+		}
+		TextContent::Literal(_) => {}
+	}
+	Ok(())
+}
+
+/// Replaces `scoped_css!(…)`, `scoped_scss!(…)` and their `tidos::` paths in
+/// `tokens` by the class name, returns whether there was one.
+fn replace_calls(tokens: &mut Vec<TokenTree>, scopes: &mut Vec<ScopedCss>) -> syn::Result<bool> {
+	let mut replaced = false;
+	let mut index = 0;
+	while index < tokens.len() {
+		match &tokens[index..] {
+			[TokenTree::Ident(name), TokenTree::Punct(bang), TokenTree::Group(arguments), ..]
+				if (name == "scoped_css" || name == "scoped_scss") && bang.as_char() == '!' =>
+			{
+				let mut scoped_css = if name == "scoped_scss" {
+					ScopedCss::parse_scss.parse2(arguments.stream())?
+				} else {
+					syn::parse2::<ScopedCss>(arguments.stream())?
+				};
+				let mut literal = Literal::string(&scoped_css.id);
+				literal.set_span(name.span());
+
+				let start = index - path_prefix_len(&tokens[..index]);
+				scoped_css.path = tokens[start..=index].iter().cloned().collect();
+				scopes.push(scoped_css);
+				tokens.splice(start..index + 3, [TokenTree::Literal(literal)]);
+				index = start;
+				replaced = true;
+			}
+			[TokenTree::Group(group), ..] => {
+				let mut inner = group.stream().into_iter().collect::<Vec<_>>();
+				if replace_calls(&mut inner, scopes)? {
+					let mut new_group = Group::new(
+						group.delimiter(),
+						inner.into_iter().collect::<TokenStream>(),
+					);
+					new_group.set_span(group.span());
+					tokens[index] = TokenTree::Group(new_group);
+					replaced = true;
+				}
+			}
+			_ => {}
+		}
+		index += 1;
+	}
+	Ok(replaced)
+}
+
+/// The length of a `tidos::` or `::tidos::` path in front of a macro name.
+fn path_prefix_len(tokens: &[TokenTree]) -> usize {
+	match tokens {
+		[rest @ .., TokenTree::Ident(krate), first, second]
+			if krate == "tidos" && is_path_separator(first, second) =>
+		{
+			match rest {
+				[.., first, second] if is_path_separator(first, second) => 5,
+				_ => 3,
+			}
+		}
+		_ => 0,
+	}
+}
+
+fn is_path_separator(first: &TokenTree, second: &TokenTree) -> bool {
+	matches!((first, second), (TokenTree::Punct(first), TokenTree::Punct(second))
+		if first.as_char() == ':' && second.as_char() == ':')
+}
+
+/// `, progress = self.pct, accent = &self.color` after the stylesheet.
+fn parse_properties(input: ParseStream) -> syn::Result<Vec<CustomProperty>> {
+	let mut properties = Vec::new();
+	while !input.is_empty() {
+		input.parse::<Token![,]>()?;
+		if input.is_empty() {
+			break;
+		}
+		let name = Ident::parse_any(input).map_err(|error| {
+			syn::Error::new(
+				error.span(),
+				"expected a custom property, e.g. `progress = self.pct`",
+			)
+		})?;
+		input.parse::<Token![=]>()?;
+		let value = input.parse::<Expr>()?;
+		properties.push(CustomProperty {
+			name: name.unraw().to_string().replace('_', "-"),
+			span: name.span(),
+			value: value.to_token_stream(),
+		});
+	}
+	Ok(properties)
+}
+
+/// CSS written as Rust tokens. The lexer drops whitespace, which matters in
+/// selectors, `a:hover` isn't `a :hover`, so it's restored from where the
+/// tokens are in the source.
+struct CssTokens {
+	css: String,
+	/// Where each token starts in `css`, to point errors at it.
+	spans: Vec<(usize, Span)>,
+	end: Option<LineColumn>,
+}
+
+impl CssTokens {
+	fn new(tokens: TokenStream) -> CssTokens {
+		let mut css = CssTokens {
+			css: String::new(),
+			spans: Vec::new(),
+			end: None,
+		};
+		css.push_stream(tokens);
+		css
+	}
+
+	fn push_stream(&mut self, tokens: TokenStream) {
+		for token in tokens {
+			match token {
+				TokenTree::Group(group) => {
+					let (open, close) = match group.delimiter() {
+						Delimiter::Brace => ("{", "}"),
+						Delimiter::Bracket => ("[", "]"),
+						Delimiter::Parenthesis => ("(", ")"),
+						Delimiter::None => ("", ""),
+					};
+					self.push(open, group.span_open());
+					self.push_stream(group.stream());
+					self.push(close, group.span_close());
+				}
+				token => self.push(&token.to_string(), token.span()),
+			}
+		}
+	}
+
+	fn push(&mut self, text: &str, span: Span) {
+		if text.is_empty() {
+			return;
+		}
+		let start = span.start();
+		match self.end {
+			Some(end) if end == start => {}
+			Some(end) if end.line < start.line => self.css.push('\n'),
+			// Tokens without a real location, e.g. from another macro, are
+			// kept apart.
+			Some(_) => self.css.push(' '),
+			None => {}
+		}
+		self.spans.push((self.css.len(), span));
+		self.css.push_str(text);
+		self.end = Some(span.end());
+	}
+
+	fn span_at(&self, offset: usize) -> Span {
+		self.spans
+			.iter()
+			.rev()
+			.find(|(start, _)| *start <= offset)
+			.map_or_else(Span::call_site, |(_, span)| *span)
+	}
+}
@@ -0,0 +1,122 @@
+use crate::source_file::SourceFile;
+use cssparser::{Parser, Token};
+use grass::{ErrorKind, Fs, Options, StdFs};
+use std::cell::RefCell;
+use std::io;
+use std::path::{Path, PathBuf};
+use syn::LitStr;
+
+/// Compiles an SCSS file with grass. `@use` and `@import` resolve relative
+/// to the file, like Sass does.
+///
+/// Returns the CSS and every file that went into it, the file itself first,
+/// so the calling crate can be rebuilt when a partial changes.
+pub fn compile(file: &SourceFile, path: &LitStr) -> syn::Result<(String, Vec<PathBuf>)> {
+	let fs = TrackingFs {
+		root: file.path.clone(),
+		root_contents: with_placeholder(&file.contents),
+		read: RefCell::default(),
+	};
+	let options = Options::default()
+		.fs(&fs)
+		.quiet(true)
+		.allows_charset(false)
+		.unicode_error_messages(false);
+
+	let css = grass::from_path(&file.path, &options).map_err(|error| match error.kind() {
+		ErrorKind::ParseError { message, loc, .. } => {
+			// The error can be in a partial, quote that file.
+			let name = PathBuf::from(loc.file.name());
+			let contents = if name == file.path {
+				file.contents.clone()
+			} else {
+				loc.file.source().to_string()
+			};
+			let source = SourceFile {
+				path: name,
+				contents,
+			};
+			source.error_at(path, &message, loc.begin.line + 1, loc.begin.column)
+		}
+		ErrorKind::IoError(error) => syn::Error::new(
+			path.span(),
+			format!("couldn't compile `{}`: {error}", file.path.display()),
+		),
+		_ => syn::Error::new(
+			path.span(),
+			format!("couldn't compile `{}`", file.path.display()),
+		),
+	})?;
+
+	let css = css.replace(PLACEHOLDER, "&");
+
+	let mut files = vec![file.path.clone()];
+	for read in fs.read.into_inner() {
+		if !files.contains(&read) {
+			files.push(read);
+		}
+	}
+	Ok((css, files))
+}
+
+/// Stands in for a top-level `&`, which Sass doesn't allow but scoped
+/// stylesheets use for the element with the scope's class.
+const PLACEHOLDER: &str = ".tidos-scss-scope";
+
+/// `source` with the `&` in its top-level selectors replaced by the
+/// placeholder. Nested `&` is Sass's own and left alone.
+fn with_placeholder(source: &str) -> String {
+	let mut output = String::with_capacity(source.len());
+	let mut parser = Parser::new(source);
+	let mut copied = 0;
+	let mut in_at_rule = false;
+	loop {
+		let start = parser.position().byte_index();
+		let token = match parser.next_including_whitespace_and_comments() {
+			Ok(token) => token.clone(),
+			Err(_) => break,
+		};
+		match token {
+			Token::AtKeyword(_) => in_at_rule = true,
+			Token::Semicolon | Token::CurlyBracketBlock => in_at_rule = false,
+			Token::Delim('&') if !in_at_rule => {
+				output.push_str(&source[copied..start]);
+				output.push_str(PLACEHOLDER);
+				copied = start + 1;
+			}
+			_ => {}
+		}
+	}
+	output.push_str(&source[copied..]);
+	output
+}
+
+/// Reads from disk, remembering which files were read. The stylesheet
+/// itself is read with its top-level `&` replaced.
+#[derive(Debug)]
+struct TrackingFs {
+	root: PathBuf,
+	root_contents: String,
+	read: RefCell<Vec<PathBuf>>,
+}
+
+impl Fs for TrackingFs {
+	fn is_dir(&self, path: &Path) -> bool {
+		StdFs.is_dir(path)
+	}
+
+	fn is_file(&self, path: &Path) -> bool {
+		StdFs.is_file(path)
+	}
+
+	fn read(&self, path: &Path) -> io::Result<Vec<u8>> {
+		let path = std::path::absolute(path)?;
+		let contents = if path == self.root {
+			self.root_contents.clone().into_bytes()
+		} else {
+			StdFs.read(&path)?
+		};
+		self.read.borrow_mut().push(path);
+		Ok(contents)
+	}
+}
@@ -0,0 +1,87 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use std::path::{Path, PathBuf};
+use syn::LitStr;
+
+/// A file read by a macro, resolved relative to the source file that invokes
+/// the macro, the same way `include_str!` resolves its path.
+#[derive(Debug)]
+pub struct SourceFile {
+	pub path: PathBuf,
+	pub contents: String,
+}
+
+impl SourceFile {
+	pub fn read(path: &LitStr) -> syn::Result<SourceFile> {
+		let path_buf = SourceFile::resolve(path)?;
+		let contents = std::fs::read_to_string(&path_buf).map_err(|error| {
+			syn::Error::new(
+				path.span(),
+				format!("couldn't read `{}`: {error}", path_buf.display()),
+			)
+		})?;
+
+		Ok(SourceFile {
+			path: path_buf,
+			contents,
+		})
+	}
+
+	/// The absolute path of the file `path` names, without reading it.
+	pub fn resolve(path: &LitStr) -> syn::Result<PathBuf> {
+		let relative = path.value();
+		let base = path
+			.span()
+			.unwrap()
+			.local_file()
+			.and_then(|file| file.parent().map(PathBuf::from))
+			.or_else(|| std::env::var_os("CARGO_MANIFEST_DIR").map(PathBuf::from))
+			.unwrap_or_default();
+		// `local_file` is relative to the compiler's working directory, while
+		// `include_bytes!` resolves relative paths against the calling file.
+		std::path::absolute(base.join(&relative)).map_err(|error| {
+			syn::Error::new(
+				path.span(),
+				format!("couldn't resolve `{relative}`: {error}"),
+			)
+		})
+	}
+
+	/// An error at `line` and the 0-based `column` in the file, quoting the
+	/// line the way rustc does. It's reported on `path`, the literal naming
+	/// the file, as spans can't point into other files.
+	pub fn error_at(&self, path: &LitStr, message: &str, line: usize, column: usize) -> syn::Error {
+		let source = self
+			.contents
+			.lines()
+			.nth(line.saturating_sub(1))
+			.unwrap_or_default();
+		let gutter = " ".repeat(line.to_string().len());
+		let caret = source
+			.chars()
+			.take(column)
+			.map(|char| if char == '\t' { '\t' } else { ' ' })
+			.collect::<String>();
+
+		syn::Error::new(
+			path.span(),
+			format!(
+				"{message}\n{gutter}--> {}:{line}:{}\n{gutter} |\n{line} | {source}\n{gutter} | {caret}^",
+				self.path.display(),
+				column + 1,
+			),
+		)
+	}
+
+	/// An item that makes the compiler track the file, so the calling crate is
+	/// rebuilt when it changes.
+	pub fn track(&self) -> TokenStream {
+		track(&self.path)
+	}
+}
+
+/// An item that makes the compiler track the file at `path`.
+pub fn track(path: &Path) -> TokenStream {
+	let path = path.to_string_lossy();
+	quote! { const _: &[u8] = include_bytes!(#path); }
+}
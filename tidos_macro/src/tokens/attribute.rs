@@ -32,7 +32,6 @@ impl IsStatic for Attribute {
 			AttributeType::ImplicitToggle => false,
 			AttributeType::ExplicitToggle { .. } => false,
 			AttributeType::Constant => true,
-			// todo identifier of scoped css is static
 			AttributeType::ConstantLiteral { .. } => true,
 			AttributeType::Expression { content } => content.is_static(),
 		}
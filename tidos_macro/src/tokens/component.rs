@@ -1,8 +1,11 @@
+use crate::scoped_css::ScopedCss;
 use crate::tokens::{Content, IsStatic};
 
 #[derive(Debug)]
 pub struct Component {
 	pub children: Vec<Content>,
+	/// The stylesheets of the `scoped_css!` used in the view's attributes.
+	pub scopes: Vec<ScopedCss>,
 }
 
 impl IsStatic for Component {
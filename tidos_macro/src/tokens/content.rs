@@ -1,6 +1,6 @@
 use crate::tokens::HTMLTag;
 use crate::tokens::{ControlTag, IsStatic};
-use proc_macro2::{Ident, Literal, TokenTree};
+use proc_macro2::{Ident, Literal, TokenStream, TokenTree};
 
 #[derive(Debug)]
 pub enum Content {
@@ -11,6 +11,8 @@ pub enum Content {
 	// {#for x in numbers} ... {/for}
 	// {#match x} ... {/match}
 	// {#slot:name} ... {/slot}
+	// {#snippet name(...)} ... {/snippet}
+	// {@let x = ...}
 	ControlTag(ControlTag),
 
 	// text
@@ -19,8 +21,31 @@ pub enum Content {
 	// <p>@html{"<p>potential danger"}</p>
 	RawHTMLExpression(TextContent),
 
+	// <p>@sanitized_html{comment.body}</p>
+	SanitizedHTMLExpression(TextContent),
+
+	// <article>@markdown{post.body}</article>
+	MarkdownExpression(TextContent),
+
 	// @slot{self.field_name}
-	SlotRender(Vec<TokenTree>),
+	// @slot{self.field_name, item}
+	// @slot{self.field_name}{:else} ... {/slot}
+	SlotRender {
+		slot: Vec<TokenTree>,
+		args: Vec<TokenTree>,
+		fallback: Option<Vec<Content>>,
+	},
+
+	// @render{row(&user)}, where `{#snippet row(…)}` is in scope
+	SnippetRender {
+		callee: Ident,
+		args: TokenStream,
+	},
+
+	// @render{self.widgets}
+	ComponentRender {
+		component: Vec<TokenTree>,
+	},
 }
 
 impl IsStatic for Content {
@@ -30,7 +55,11 @@ impl IsStatic for Content {
 			Content::ControlTag(_) => false,
 			Content::Text(content) => content.is_static(),
 			Content::RawHTMLExpression(content) => content.is_static(),
-			Content::SlotRender(_) => false,
+			Content::SanitizedHTMLExpression(_) => false,
+			Content::MarkdownExpression(_) => false,
+			Content::SlotRender { .. } => false,
+			Content::SnippetRender { .. } => false,
+			Content::ComponentRender { .. } => false,
 		}
 	}
 }
@@ -56,8 +85,20 @@ pub enum TypeOfCommandTag {
 	For {
 		left_side: Vec<TokenTree>,
 		right_side: Vec<TokenTree>,
+		loop_binding: Option<Ident>,
 	},
 	If(Vec<TokenTree>),
 	Match(Vec<TokenTree>),
-	Slot(Ident),
+	Slot {
+		name: Ident,
+		params: Option<TokenStream>,
+	},
+	Snippet {
+		name: Ident,
+		params: TokenStream,
+	},
+	Let {
+		pattern: Vec<TokenTree>,
+		value: Vec<TokenTree>,
+	},
 }
@@ -1,5 +1,5 @@
 use crate::tokens::Content;
-use proc_macro2::{Ident, TokenTree};
+use proc_macro2::{Ident, TokenStream, TokenTree};
 
 #[derive(Debug)]
 pub enum ControlTag {
@@ -10,11 +10,13 @@ pub enum ControlTag {
 		if_else_chain: Vec<(Vec<TokenTree>, Vec<Content>)>,
 		else_content: Option<Vec<Content>>,
 	},
-	/// {#for ... in ... } ... {/for}
+	/// {#for ... in ...; loop } ... {:empty} ... {/for}
 	For {
 		left_side: Vec<TokenTree>,
 		right_side: Vec<TokenTree>,
+		loop_binding: Option<Ident>,
 		contents: Vec<Content>,
+		empty_contents: Option<Vec<Content>>,
 	},
 	/// {#match ... } ... {/match}
 	Match {
@@ -22,5 +24,21 @@ pub enum ControlTag {
 		cases: Vec<(Vec<TokenTree>, Vec<Content>)>,
 	},
 	/// {#slot:name } ... {/slot}
-	Slot { name: Ident, contents: Vec<Content> },
+	/// {#slot:name |item| } ... {/slot}
+	Slot {
+		name: Ident,
+		params: Option<TokenStream>,
+		contents: Vec<Content>,
+	},
+	/// {#snippet name(...) } ... {/snippet}
+	Snippet {
+		name: Ident,
+		params: TokenStream,
+		contents: Vec<Content>,
+	},
+	/// {@let ... = ... }
+	Let {
+		pattern: Vec<TokenTree>,
+		value: Vec<TokenTree>,
+	},
 }
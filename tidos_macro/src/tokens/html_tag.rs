@@ -1,12 +1,17 @@
 use crate::tokens::Content;
 use crate::tokens::{Attribute, IsStatic};
-use proc_macro2::Span;
+use proc_macro2::{Span, TokenStream};
 
 #[derive(Debug)]
 pub struct HTMLTag {
+	/// The tag name as written, e.g. `div`, `my-element` or `ui::Card<User>`,
+	/// used to match the closing tag.
 	pub tag: String,
 	pub tag_span: Span,
-	pub closing_tag_span: Option<Span>,
+	/// For components, the path to the component's type in expression form,
+	/// e.g. `ui::Card` or `List::<User>`.
+	pub component_path: Option<TokenStream>,
+	pub closing_component_path: Option<TokenStream>,
 	pub attributes: Attributes,
 	pub children: Vec<Content>,
 	pub is_self_closing: bool,
@@ -20,7 +25,7 @@ pub struct Attributes {
 
 impl HTMLTag {
 	pub fn is_component(&self) -> bool {
-		self.tag.chars().next().unwrap().is_ascii_uppercase()
+		self.component_path.is_some()
 	}
 }
 
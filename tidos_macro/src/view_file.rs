@@ -0,0 +1,227 @@
+use crate::source_file::SourceFile;
+use crate::tokens::Component;
+use proc_macro2::{Delimiter, Group, Span, TokenStream, TokenTree};
+use quote::{quote, ToTokens, TokenStreamExt};
+use syn::parse::{Parse, ParseStream};
+use syn::LitStr;
+
+/// `view_file!("./layout.tidos")`, a template read from a file and parsed
+/// like the body of `view!`.
+pub struct ViewFile {
+	file: SourceFile,
+	component: Component,
+}
+
+impl Parse for ViewFile {
+	fn parse(input: ParseStream) -> syn::Result<Self> {
+		let path = input.parse::<LitStr>().map_err(|error| {
+			syn::Error::new(
+				error.span(),
+				"expected the path to a template, e.g. `view_file!(\"./layout.tidos\")`",
+			)
+		})?;
+		if !input.is_empty() {
+			return Err(input.error("unexpected tokens after the template path"));
+		}
+
+		let file = SourceFile::read(&path)?;
+		let component = file
+			.contents
+			.parse::<TokenStream>()
+			.map_err(|error| syn::Error::new(path.span(), error.to_string()))
+			.and_then(syn::parse2::<Component>)
+			.map_err(|error| locate(&file, &path, error))?;
+
+		Ok(ViewFile { file, component })
+	}
+}
+
+impl ToTokens for ViewFile {
+	fn to_tokens(&self, tokens: &mut TokenStream) {
+		let track = self.file.track();
+		let component = &self.component;
+		tokens.append_all(quote! {
+			{
+				#track
+				#component
+			}
+		});
+	}
+}
+
+/// Tokens parsed from a string all share the span of the macro call, so the
+/// error can't say where in the file it happened. To find the token it points
+/// at, the template is parsed again with the tokens from some index onward
+/// spanned to the path literal and the rest to the call site, bisecting on
+/// which of the two the error ends up with. The token is then found in the
+/// text to get its line and column.
+fn locate(file: &SourceFile, path: &LitStr, error: syn::Error) -> syn::Error {
+	let offset = match file.contents.parse::<TokenStream>() {
+		Ok(tokens) => failing_token(&tokens, path.span())
+			.and_then(|index| token_offset(&file.contents, &tokens, index)),
+		Err(_) => lex_error_offset(&file.contents),
+	};
+
+	let Some(offset) = offset else {
+		return syn::Error::new(path.span(), error.to_string());
+	};
+	let before = &file.contents[..offset];
+	let line = before.matches('\n').count() + 1;
+	let column = before[before.rfind('\n').map_or(0, |newline| newline + 1)..]
+		.chars()
+		.count();
+	file.error_at(path, &error.to_string(), line, column)
+}
+
+/// The index, in the order [`flatten`] visits them, of the token the parse
+/// error points at.
+fn failing_token(tokens: &TokenStream, marker: Span) -> Option<usize> {
+	let count = flatten(tokens).len();
+	let points_at_or_after = |index: usize| {
+		let mut next = 0;
+		let respanned = respan(tokens.clone(), &mut next, &|token| {
+			if token < index {
+				Span::call_site()
+			} else {
+				marker
+			}
+		});
+		syn::parse2::<Component>(respanned).is_err_and(|error| {
+			let span = error.span();
+			span.start() == marker.start() && span.source_text() == marker.source_text()
+		})
+	};
+
+	// Errors at the end of the input, or not at any token, aren't spanned to
+	// the marker even when every token is.
+	if !points_at_or_after(0) {
+		return None;
+	}
+	let (mut low, mut high) = (0, count);
+	while low + 1 < high {
+		let middle = (low + high) / 2;
+		if points_at_or_after(middle) {
+			low = middle;
+		} else {
+			high = middle;
+		}
+	}
+	Some(low)
+}
+
+fn respan(tokens: TokenStream, next: &mut usize, span_of: &dyn Fn(usize) -> Span) -> TokenStream {
+	tokens
+		.into_iter()
+		.map(|mut token| {
+			let span = span_of(*next);
+			*next += 1;
+			if let TokenTree::Group(group) = &token {
+				let mut respanned =
+					Group::new(group.delimiter(), respan(group.stream(), next, span_of));
+				respanned.set_span(span);
+				token = TokenTree::Group(respanned);
+			} else {
+				token.set_span(span);
+			}
+			token
+		})
+		.collect()
+}
+
+/// Every token in source order, a group before the tokens inside it.
+fn flatten(tokens: &TokenStream) -> Vec<TokenTree> {
+	let mut flat = Vec::new();
+	for token in tokens.clone() {
+		flat.push(token.clone());
+		if let TokenTree::Group(group) = token {
+			flat.extend(flatten(&group.stream()));
+		}
+	}
+	flat
+}
+
+/// The byte offset of token `index` in `source`, found by searching for the
+/// text of each token in turn.
+fn token_offset(source: &str, tokens: &TokenStream, index: usize) -> Option<usize> {
+	let mut offset = 0;
+	for (position, token) in flatten(tokens).into_iter().enumerate() {
+		let text = match token {
+			TokenTree::Group(group) => match group.delimiter() {
+				Delimiter::Parenthesis => "(".to_string(),
+				Delimiter::Brace => "{".to_string(),
+				Delimiter::Bracket => "[".to_string(),
+				Delimiter::None => continue,
+			},
+			TokenTree::Punct(punct) => punct.as_char().to_string(),
+			token => token.to_string(),
+		};
+		let start = offset + source[offset..].find(&text)?;
+		if position == index {
+			return Some(start);
+		}
+		offset = start + text.len();
+	}
+	None
+}
+
+/// The byte offset where the template stops lexing: a delimiter closed by
+/// the wrong one, an unterminated string or comment, or else the innermost
+/// delimiter left open.
+fn lex_error_offset(source: &str) -> Option<usize> {
+	let mut open = Vec::new();
+	let mut chars = source.char_indices().peekable();
+	while let Some((offset, char)) = chars.next() {
+		match char {
+			'(' | '[' | '{' => open.push((char, offset)),
+			')' | ']' | '}' => match open.pop() {
+				Some(('(', _)) if char == ')' => {}
+				Some(('[', _)) if char == ']' => {}
+				Some(('{', _)) if char == '}' => {}
+				_ => return Some(offset),
+			},
+			'"' => loop {
+				match chars.next() {
+					Some((_, '\\')) => {
+						chars.next();
+					}
+					Some((_, '"')) => break,
+					Some(_) => {}
+					None => return Some(offset),
+				}
+			},
+			'\'' => {
+				// A character literal, unless it's a lifetime.
+				let rest = &source[offset + 1..];
+				let length = match rest.strip_prefix('\\') {
+					Some(escaped) => escaped.find('\'').map(|end| end + 2),
+					None => rest
+						.chars()
+						.next()
+						.filter(|first| rest[first.len_utf8()..].starts_with('\''))
+						.map(|first| first.len_utf8() + 1),
+				};
+				if let Some(length) = length {
+					while chars
+						.next_if(|(next, _)| *next <= offset + length)
+						.is_some()
+					{}
+				}
+			}
+			'/' if chars.next_if(|(_, next)| *next == '/').is_some() => {
+				while chars.next_if(|(_, next)| *next != '\n').is_some() {}
+			}
+			'/' if chars.next_if(|(_, next)| *next == '*').is_some() => {
+				let mut previous = ' ';
+				loop {
+					match chars.next() {
+						Some((_, '/')) if previous == '*' => break,
+						Some((_, next)) => previous = next,
+						None => return Some(offset),
+					}
+				}
+			}
+			_ => {}
+		}
+	}
+	open.pop().map(|(_, offset)| offset)
+}